@@ -0,0 +1,112 @@
+// A global allocator that counts allocations, once counting is enabled, so
+// that benchmarks can report memory use alongside time
+
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::fmt;
+use std::sync::atomic::{AtomicBool, AtomicI64, AtomicU64, Ordering};
+
+struct Counting;
+
+#[global_allocator]
+static GLOBAL: Counting = Counting;
+
+static ENABLED: AtomicBool = AtomicBool::new(false);
+static ALLOCS: AtomicU64 = AtomicU64::new(0);
+static BYTES: AtomicU64 = AtomicU64::new(0);
+// Live bytes can go below zero when blocks allocated before counting was
+// enabled are freed, which doesn't matter since only differences are used
+static LIVE: AtomicI64 = AtomicI64::new(0);
+static PEAK: AtomicI64 = AtomicI64::new(0);
+
+fn allocated(size: usize) {
+	ALLOCS.fetch_add(1, Ordering::Relaxed);
+	BYTES.fetch_add(size as u64, Ordering::Relaxed);
+	let live = LIVE.fetch_add(size as i64, Ordering::Relaxed) + size as i64;
+	PEAK.fetch_max(live, Ordering::Relaxed);
+}
+
+fn freed(size: usize) {
+	LIVE.fetch_sub(size as i64, Ordering::Relaxed);
+}
+
+unsafe impl GlobalAlloc for Counting {
+	unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+		let ptr = System.alloc(layout);
+		if !ptr.is_null() && ENABLED.load(Ordering::Relaxed) {
+			allocated(layout.size());
+		}
+		ptr
+	}
+
+	unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+		let ptr = System.alloc_zeroed(layout);
+		if !ptr.is_null() && ENABLED.load(Ordering::Relaxed) {
+			allocated(layout.size());
+		}
+		ptr
+	}
+
+	unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+		System.dealloc(ptr, layout);
+		if ENABLED.load(Ordering::Relaxed) {
+			freed(layout.size());
+		}
+	}
+
+	// A reallocation counts as a new allocation of the new size
+	unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+		let new = System.realloc(ptr, layout, new_size);
+		if !new.is_null() && ENABLED.load(Ordering::Relaxed) {
+			freed(layout.size());
+			allocated(new_size);
+		}
+		new
+	}
+}
+
+/// Starts counting allocations, for the rest of the process.
+pub fn enable() {
+	ENABLED.store(true, Ordering::Relaxed);
+}
+
+/// What a piece of code allocated. The peak is the most bytes it had live
+/// at once, on top of what was live before it ran.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Usage {
+	pub allocs: u64,
+	pub bytes: u64,
+	pub peak: u64,
+}
+
+/// Runs `f` and counts its allocations, which is only meaningful once
+/// counting is enabled and while no other thread allocates.
+pub fn measure<T>(f: impl FnOnce() -> T) -> (T, Usage) {
+	let allocs = ALLOCS.load(Ordering::Relaxed);
+	let bytes = BYTES.load(Ordering::Relaxed);
+	let live = LIVE.load(Ordering::Relaxed);
+	PEAK.store(live, Ordering::Relaxed);
+
+	let res = f();
+	let usage = Usage {
+		allocs: ALLOCS.load(Ordering::Relaxed) - allocs,
+		bytes: BYTES.load(Ordering::Relaxed) - bytes,
+		peak: (PEAK.load(Ordering::Relaxed) - live).max(0) as u64,
+	};
+	(res, usage)
+}
+
+// Formats a byte count with a binary unit suited to its magnitude
+pub struct HumanBytes(pub u64);
+
+impl fmt::Display for HumanBytes {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		let b = self.0;
+		let s = match b {
+			0..=1023 => format!("{}B", b),
+			1024..=0xfffff => format!("{:.2}KiB", b as f64 / 1024.0),
+			0x100000..=0x3fffffff => format!("{:.2}MiB", b as f64 / (1 << 20) as f64),
+			_ => format!("{:.2}GiB", b as f64 / (1 << 30) as f64),
+		};
+		f.pad(&s)
+	}
+}
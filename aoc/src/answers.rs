@@ -0,0 +1,54 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use common::Error;
+use common::toml::{Document, Value};
+
+// Known answers for an input, stored next to it and named after it, as
+// `part1 = "..."` and `part2 = "..."`: `alice.answers.toml` for `alice.txt`,
+// except for a day's own `input.txt`, whose answers are in `answers.toml`.
+// Inputs sharing a directory never share answers.
+pub struct Known {
+	path: PathBuf,
+	doc: Document,
+}
+
+impl Known {
+	pub fn load(input: &Path) -> Result<Self, Error> {
+		let path = match input.file_name().is_some_and(|n| n == "input.txt") {
+			true => input.with_file_name("answers.toml"),
+			false => input.with_extension("answers.toml"),
+		};
+		let doc = match path.exists() {
+			true => {
+				let src = fs::read_to_string(&path)?;
+				Document::parse(&src).map_err(|e| format!("{}: {}",
+					path.display(), e.snippet(&src)))?
+			}
+			false => Document::new(),
+		};
+		Ok(Self { path, doc })
+	}
+
+	pub fn exists(&self) -> bool {
+		self.path.exists()
+	}
+
+	pub fn get(&self, part: u8) -> Option<&str> {
+		self.doc.get_str(&format!("part{}", part))
+	}
+
+	// Returns the expected answer if it differs from the given one
+	pub fn check(&self, part: u8, answer: &str) -> Option<&str> {
+		self.get(part).filter(|known| *known != answer)
+	}
+
+	pub fn record(&mut self, part: u8, answer: &str) {
+		self.doc.set(format!("part{}", part), Value::String(answer.to_string()));
+	}
+
+	pub fn save(&self) -> Result<(), Error> {
+		fs::write(&self.path, self.doc.to_string())?;
+		Ok(())
+	}
+}
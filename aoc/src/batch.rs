@@ -0,0 +1,26 @@
+use std::path::PathBuf;
+use std::time::Duration;
+
+use crate::bench::Human;
+use crate::pool::Report;
+
+pub fn print_table(files: &[PathBuf], reports: &[Report], total: Duration) {
+	let names: Vec<String> = files.iter().map(|f| f.display().to_string()).collect();
+	let width = names.iter().map(|n| n.chars().count()).max().unwrap_or(0).max(4);
+	println!("{:<width$}  {:<16}  {:<16}  {:>10}  Status",
+		"File", "Part 1", "Part 2", "Time");
+	for (name, r) in names.iter().zip(reports) {
+		let (p1, p2, status) = r.summary();
+		println!("{:<width$}  {:<16}  {:<16}  {:>10}  {}",
+			name, p1, p2, Human(r.wall), status);
+	}
+
+	let failed: Vec<_> = names.iter().zip(reports).filter(|(_, r)| !r.ok()).collect();
+	println!("{} files in {}, {} failed", files.len(), Human(total), failed.len());
+	if !failed.is_empty() {
+		println!("\nFailed:");
+		for (name, r) in failed {
+			println!("  {}: {}", name, r.summary().2);
+		}
+	}
+}
@@ -0,0 +1,128 @@
+use std::fmt;
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+use common::{Error, Params, Solution};
+use common::progress;
+
+use crate::alloc::{self, HumanBytes, Usage};
+
+pub struct Stats {
+	pub min: Duration,
+	pub median: Duration,
+	pub max: Duration,
+}
+
+impl Stats {
+	fn new(mut samples: Vec<Duration>) -> Self {
+		samples.sort();
+		Self {
+			min: samples[0],
+			median: samples[samples.len() / 2],
+			max: samples[samples.len() - 1],
+		}
+	}
+}
+
+pub struct Memory {
+	pub parse: Usage,
+	pub part1: Usage,
+	pub part2: Usage,
+}
+
+pub struct Timings {
+	pub parse: Stats,
+	pub part1: Stats,
+	pub part2: Stats,
+	pub memory: Option<Memory>,
+}
+
+// Runs `f` `iters` times, returning the last result and every duration,
+// or the first error
+fn sample<T, E, F>(iters: usize, mut f: F) -> Result<(T, Stats), E>
+where
+	F: FnMut() -> Result<T, E>,
+{
+	let mut samples = Vec::with_capacity(iters);
+	let mut out = None;
+	for _ in 0..iters.max(1) {
+		let start = Instant::now();
+		let res = black_box(f())?;
+		samples.push(start.elapsed());
+		out = Some(res);
+	}
+	Ok((out.unwrap(), Stats::new(samples)))
+}
+
+// Times a part, each run of which has to fit in the time budget
+fn sample_part<T>(iters: usize, part: u8, f: impl Fn() -> T) -> Result<Stats, Error> {
+	let label = format!("Part {}", part);
+	sample(iters, || progress::run(&label, &f))
+		.map(|(_, stats)| stats)
+		.map_err(|e| format!("part {} {}", part, e).into())
+}
+
+// Counts the allocations of each phase. They don't change from one run to
+// the next, so a single run of each is enough.
+fn memory<S: Solution>(inp: &str, input: &S::Input) -> Memory {
+	let (_, parse) = alloc::measure(|| S::parse(black_box(inp)));
+	let (_, part1) = alloc::measure(|| S::part1(black_box(input)));
+	let (_, part2) = alloc::measure(|| S::part2(black_box(input)));
+	Memory { parse, part1, part2 }
+}
+
+pub fn time<S: Solution>(inp: &str, iters: usize, params: &Params, mem: bool)
+	-> Result<Timings, Error>
+{
+	let (mut input, parse) = sample(iters, || S::parse(black_box(inp)))
+		.map_err(|e| e.snippet(inp))?;
+	S::configure(&mut input, params)?;
+	let part1 = sample_part(iters, 1, || S::part1(black_box(&input)))?;
+	let part2 = sample_part(iters, 2, || S::part2(black_box(&input)))?;
+	let memory = mem.then(|| memory::<S>(inp, &input));
+	Ok(Timings { parse, part1, part2, memory })
+}
+
+// Formats a duration with a unit suited to its magnitude
+pub struct Human(pub Duration);
+
+impl fmt::Display for Human {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		let ns = self.0.as_nanos();
+		let s = match ns {
+			0..=999 => format!("{}ns", ns),
+			1_000..=999_999 => format!("{:.2}µs", ns as f64 / 1e3),
+			1_000_000..=999_999_999 => format!("{:.2}ms", ns as f64 / 1e6),
+			_ => format!("{:.2}s", ns as f64 / 1e9),
+		};
+		f.pad(&s)
+	}
+}
+
+pub fn print_table(rows: &[(u8, Timings)]) {
+	let mem = rows.iter().any(|(_, t)| t.memory.is_some());
+	print!("{:>3}  {:<6}  {:>10}  {:>10}  {:>10}",
+		"Day", "Phase", "min", "median", "max");
+	if mem {
+		print!("  {:>10}  {:>10}  {:>10}", "allocs", "allocated", "peak");
+	}
+	println!();
+	for (day, t) in rows {
+		let usage = match &t.memory {
+			Some(m) => [Some(m.parse), Some(m.part1), Some(m.part2)],
+			None => [None; 3],
+		};
+		let phases = [("parse", &t.parse), ("part1", &t.part1), ("part2", &t.part2)];
+		for ((phase, st), usage) in phases.into_iter().zip(usage) {
+			print!("{:>3}  {:<6}  {:>10}  {:>10}  {:>10}", day, phase,
+				Human(st.min), Human(st.median), Human(st.max));
+			match usage {
+				Some(u) => print!("  {:>10}  {:>10}  {:>10}",
+					u.allocs, HumanBytes(u.bytes), HumanBytes(u.peak)),
+				None if mem => print!("  {:>10}  {:>10}  {:>10}", "-", "-", "-"),
+				None => (),
+			}
+			println!();
+		}
+	}
+}
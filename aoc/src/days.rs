@@ -0,0 +1,170 @@
+use std::panic::{self, AssertUnwindSafe};
+use std::time::{Duration, Instant};
+
+use common::{Error, Generate, Json, Params, Rng, Solution, ToJson, panic_message};
+use common::progress::{self, TimedOut};
+
+use visual::Visualize;
+
+use crate::bench::{self, Timings};
+
+pub const NUM_DAYS: u8 = 15;
+
+// The answer to a single part, as text and as JSON, with how long solving
+// it took. A part that ran out of time has no answer, and says so in `text`.
+pub struct Answer {
+	pub part: u8,
+	pub text: String,
+	pub json: Json,
+	pub duration: Duration,
+	pub timed_out: Option<TimedOut>,
+}
+
+pub type Answers = Vec<Answer>;
+
+fn answer<T: ToString + ToJson>(day: u8, part: u8, f: impl FnOnce() -> T) -> Answer {
+	let start = Instant::now();
+	let res = progress::run(&format!("Day {} part {}", day, part), f);
+	let duration = start.elapsed();
+	match res {
+		Ok(ans) => Answer { part, text: ans.to_string(), json: ans.to_json(),
+			duration, timed_out: None },
+		Err(e) => Answer { part, text: e.to_string(), json: Json::Null,
+			duration, timed_out: Some(e) },
+	}
+}
+
+// Fails if any of the parts ran out of time
+pub fn check_timeouts(answers: &Answers) -> Result<(), Error> {
+	match answers.iter().find_map(|a| a.timed_out.map(|e| (a.part, e))) {
+		Some((part, e)) => Err(format!("part {} {}", part, e).into()),
+		None => Ok(()),
+	}
+}
+
+fn generate<S: Generate>(seed: u64, scale: usize) -> Result<String, Error> {
+	Ok(S::generate(&mut Rng::new(seed), scale))
+}
+
+// Parses the input and applies the day's parameters to it
+pub fn parse<S: Solution>(inp: &str, params: &Params) -> Result<S::Input, Error> {
+	let mut input = S::parse(inp).map_err(|e| e.snippet(inp))?;
+	S::configure(&mut input, params)?;
+	Ok(input)
+}
+
+fn parse_only<S: Solution>(inp: &str) -> Result<(), Error> {
+	S::parse(inp).map(|_| ()).map_err(|e| e.snippet(inp).into())
+}
+
+fn parse_json<S: Solution>(inp: &str, params: &Params) -> Result<Json, Error> {
+	Ok(parse::<S>(inp, params)?.to_json())
+}
+
+fn solve<S: Solution>(day: u8, inp: &str, part: Option<u8>, params: &Params)
+	-> Result<Answers, Error>
+{
+	let input = parse::<S>(inp, params)?;
+	let mut answers = Answers::new();
+	if part != Some(2) {
+		answers.push(answer(day, 1, || S::part1(&input)));
+	}
+	if part != Some(1) {
+		answers.push(answer(day, 2, || S::part2(&input)));
+	}
+	Ok(answers)
+}
+
+// A solved part, or the part that panicked with its message
+pub type Caught = Result<Answer, (u8, String)>;
+
+fn solve_caught<S: Solution>(day: u8, inp: &str, params: &Params)
+	-> Result<Vec<Caught>, Error>
+{
+	let input = parse::<S>(inp, params)?;
+	let catch = |part, f: &dyn Fn() -> Answer| panic::catch_unwind(AssertUnwindSafe(f))
+		.map_err(|payload| (part, panic_message(&*payload)));
+	Ok(vec![
+		catch(1, &|| answer(day, 1, || S::part1(&input))),
+		catch(2, &|| answer(day, 2, || S::part2(&input))),
+	])
+}
+
+fn replay<S: Visualize>(inp: &str, part: u8, params: &Params) -> Result<(), Error> {
+	let input = parse::<S>(inp, params)?;
+	visual::play(&*S::simulation(&input, part))?;
+	Ok(())
+}
+
+// Calls the given generic function with the solution type for a day
+macro_rules! dispatch {
+	($day:expr, $($f:ident)::+($($arg:expr),*)) => {
+		match $day {
+			1 => $($f)::+::<day1::Day1>($($arg),*),
+			2 => $($f)::+::<day2::Day2>($($arg),*),
+			3 => $($f)::+::<day3::Day3>($($arg),*),
+			4 => $($f)::+::<day4::Day4>($($arg),*),
+			5 => $($f)::+::<day5::Day5>($($arg),*),
+			6 => $($f)::+::<day6::Day6>($($arg),*),
+			7 => $($f)::+::<day7::Day7>($($arg),*),
+			8 => $($f)::+::<day8::Day8>($($arg),*),
+			9 => $($f)::+::<day9::Day9>($($arg),*),
+			10 => $($f)::+::<day10::Day10>($($arg),*),
+			11 => $($f)::+::<day11::Day11>($($arg),*),
+			12 => $($f)::+::<day12::Day12>($($arg),*),
+			13 => $($f)::+::<day13::Day13>($($arg),*),
+			14 => $($f)::+::<day14::Day14>($($arg),*),
+			15 => $($f)::+::<day15::Day15>($($arg),*),
+			day => Err(format!("no such day: {}", day).into()),
+		}
+	};
+}
+
+// Parses the input for the given day and solves the requested part, or
+// both if none is given
+pub fn run(day: u8, inp: &str, part: Option<u8>, params: &Params)
+	-> Result<Answers, Error>
+{
+	dispatch!(day, solve(day, inp, part, params))
+}
+
+// Like `run` for both parts, except that a part that panics gives its
+// panic message, so that the other part's answer isn't lost with it
+pub fn run_caught(day: u8, inp: &str, params: &Params) -> Result<Vec<Caught>, Error> {
+	dispatch!(day, solve_caught(day, inp, params))
+}
+
+// Checks whether the input parses for the given day, without solving it
+pub fn check_parse(day: u8, inp: &str) -> Result<(), Error> {
+	dispatch!(day, parse_only(inp))
+}
+
+// Parses the input for the given day into JSON, with its parameters applied
+pub fn parsed(day: u8, inp: &str, params: &Params) -> Result<Json, Error> {
+	dispatch!(day, parse_json(inp, params))
+}
+
+// Times each phase of the given day over `iters` iterations, and counts
+// their allocations if `mem` is set
+pub fn bench(day: u8, inp: &str, iters: usize, params: &Params, mem: bool)
+	-> Result<Timings, Error>
+{
+	dispatch!(day, bench::time(inp, iters, params, mem))
+}
+
+// Generates a random input for the given day
+pub fn gen(day: u8, seed: u64, scale: usize) -> Result<String, Error> {
+	dispatch!(day, generate(seed, scale))
+}
+
+// Replays the simulation behind a part of the given day in the terminal
+pub fn visualize(day: u8, inp: &str, part: u8, params: &Params) -> Result<(), Error> {
+	match day {
+		5 => replay::<day5::Day5>(inp, part, params),
+		9 => replay::<day9::Day9>(inp, part, params),
+		10 => replay::<day10::Day10>(inp, part, params),
+		11 => replay::<day11::Day11>(inp, part, params),
+		14 => replay::<day14::Day14>(inp, part, params),
+		_ => Err("no visualization for this day".into()),
+	}
+}
@@ -0,0 +1,35 @@
+use common::Error;
+
+// Classic delta debugging: tries removing ever smaller chunks of `items`,
+// keeping every removal after which `test` still holds. An item is always
+// left, since an empty input tends to fail for reasons of its own.
+pub fn ddmin<T, F>(mut items: Vec<T>, mut test: F) -> Result<Vec<T>, Error>
+where
+	T: Clone,
+	F: FnMut(&[T]) -> Result<bool, Error>,
+{
+	let mut n = 2;
+	while items.len() >= 2 {
+		let chunk = items.len().div_ceil(n);
+		let mut removed = false;
+		for start in (0..items.len()).step_by(chunk) {
+			let rest: Vec<T> = items[..start].iter()
+				.chain(items.get(start + chunk..).unwrap_or(&[]))
+				.cloned()
+				.collect();
+			if test(&rest)? {
+				items = rest;
+				n = (n - 1).max(2);
+				removed = true;
+				break;
+			}
+		}
+		if !removed {
+			if n >= items.len() {
+				break;
+			}
+			n = (2 * n).min(items.len());
+		}
+	}
+	Ok(items)
+}
@@ -0,0 +1,146 @@
+// Guesses which day an input belongs to, from whether each day's parser
+// accepts it and how closely it looks like that day's format
+
+use crate::days::{self, NUM_DAYS};
+
+// The least score a day needs to be a candidate at all, which a parser that
+// accepts the input on its own doesn't reach
+const MIN_SCORE: f64 = 0.1;
+
+fn is_number(s: &str) -> bool {
+	let digits = s.strip_prefix('-').unwrap_or(s);
+	!digits.is_empty() && digits.chars().all(|c| c.is_ascii_digit())
+}
+
+// The fraction of non-empty lines for which `pred` holds
+fn fraction(inp: &str, pred: impl Fn(&str) -> bool) -> f64 {
+	let lines: Vec<_> = inp.lines().filter(|l| !l.is_empty()).collect();
+	match lines.len() {
+		0 => 0.0,
+		n => lines.iter().filter(|l| pred(l)).count() as f64 / n as f64,
+	}
+}
+
+// Whether the input is a rectangle of at least two rows of characters
+// satisfying `pred`
+fn is_grid(inp: &str, pred: impl Fn(char) -> bool) -> bool {
+	let lines: Vec<_> = inp.lines().collect();
+	lines.len() > 1
+		&& lines.iter().all(|l| !l.is_empty() && l.len() == lines[0].len())
+		&& lines.iter().flat_map(|l| l.chars()).all(pred)
+}
+
+fn is_range_pair(l: &str) -> bool {
+	l.split_once(',').is_some_and(|(a, b)| [a, b].iter().all(|r| r
+		.split_once('-')
+		.is_some_and(|(lo, hi)| is_number(lo) && is_number(hi))))
+}
+
+fn is_rock_path(l: &str) -> bool {
+	l.split(" -> ").all(|p| p
+		.split_once(',')
+		.is_some_and(|(x, y)| is_number(x) && is_number(y)))
+}
+
+// How much the input looks like the given day's, from 0 to 1, going by the
+// features that set each format apart from the others
+fn shape(day: u8, inp: &str) -> f64 {
+	let blocks = inp.split("\n\n").filter(|b| !b.trim().is_empty()).count();
+	match day {
+		// Groups of numbers; without blank lines, any list of numbers
+		1 => match fraction(inp, is_number) == 1.0 {
+			true if blocks > 1 => 1.0,
+			true => 0.3,
+			false => 0.0,
+		},
+		2 => fraction(inp, |l| matches!(l.as_bytes(),
+			[b'A'..=b'C', b' ', b'X'..=b'Z'])),
+		// Many rucksacks of varying sizes, unlike the rows of a grid or
+		// day 6's single line
+		3 => {
+			let letters = fraction(inp, |l| l.len() % 2 == 0
+				&& l.chars().all(|c| c.is_ascii_alphabetic()));
+			let grid = is_grid(inp, |c| c.is_ascii_alphabetic());
+			match grid || inp.lines().count() == 1 {
+				true => letters * 0.3,
+				false => letters,
+			}
+		}
+		4 => fraction(inp, is_range_pair),
+		5 => {
+			let moves = fraction(inp, |l| l.starts_with("move ")
+				&& l.contains(" from ") && l.contains(" to "));
+			let crates = fraction(inp, |l| l.trim_start().starts_with('['));
+			match moves > 0.0 && crates > 0.0 {
+				true => moves + crates,
+				false => 0.0,
+			}
+		}
+		6 => match inp.lines().count() == 1 {
+			true => fraction(inp, |l| l.chars().all(|c| c.is_ascii_lowercase())),
+			false => 0.0,
+		},
+		7 => fraction(inp, |l| l.starts_with("$ ") || l.starts_with("dir ")
+			|| l.split_once(' ').is_some_and(|(size, _)| is_number(size))),
+		8 => match is_grid(inp, |c| c.is_ascii_digit()) {
+			true => 1.0,
+			false => 0.0,
+		},
+		9 => fraction(inp, |l| l.split_once(' ').is_some_and(|(dir, n)|
+			["R", "L", "U", "D"].contains(&dir) && is_number(n))),
+		10 => fraction(inp, |l| l == "noop" || l.starts_with("addx ")),
+		11 => match inp.starts_with("Monkey ") {
+			true => fraction(inp, |l| l.starts_with("Monkey ")
+				|| l.starts_with("  ")),
+			false => 0.0,
+		},
+		12 => {
+			let marks = |m| inp.chars().filter(|&c| c == m).count();
+			let letters = is_grid(inp, |c| matches!(c, 'a'..='z' | 'S' | 'E'));
+			match letters && marks('S') == 1 && marks('E') == 1 {
+				true => 1.0,
+				false => 0.0,
+			}
+		}
+		13 => fraction(inp, |l| l.starts_with('[') && l.ends_with(']')),
+		14 => fraction(inp, |l| l.contains(" -> ") && is_rock_path(l)),
+		15 => fraction(inp, |l| l.starts_with("Sensor at x=")
+			&& l.contains(": closest beacon is at x=")),
+		_ => 0.0,
+	}
+}
+
+/// How likely an input is to belong to a day.
+pub struct Candidate {
+	pub day: u8,
+	pub parses: bool,
+	/// Share of the total score of all days, from 0 to 1
+	pub confidence: f64,
+}
+
+/// Scores every day on the input, most likely first. The shape of the input
+/// counts most, since some parsers accept nearly anything, but inputs that
+/// only look right and don't parse count for a quarter. Inputs that look
+/// like no day at all have no candidates.
+pub fn detect(inp: &str) -> Vec<Candidate> {
+	let scores: Vec<_> = (1..=NUM_DAYS)
+		.map(|day| {
+			let parses = days::check_parse(day, inp).is_ok();
+			let shape = shape(day, inp);
+			let score = match parses {
+				true => 0.05 + shape,
+				false => 0.25 * shape,
+			};
+			(day, parses, score)
+		})
+		.collect();
+
+	let total: f64 = scores.iter().map(|(.., score)| score).sum();
+	let mut candidates: Vec<_> = scores.into_iter()
+		.filter(|(.., score)| *score >= MIN_SCORE)
+		.map(|(day, parses, score)|
+			Candidate { day, parses, confidence: score / total })
+		.collect();
+	candidates.sort_by(|a, b| b.confidence.total_cmp(&a.confidence));
+	candidates
+}
@@ -0,0 +1,65 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use common::Error;
+
+// Whether `name` matches a pattern where `*` stands for any run of
+// characters and `?` for any single one
+pub fn matches(pattern: &[char], name: &[char]) -> bool {
+	match (pattern.first(), name.first()) {
+		(None, None) => true,
+		(Some('*'), _) => matches(&pattern[1..], name)
+			|| (!name.is_empty() && matches(pattern, &name[1..])),
+		(Some('?'), Some(_)) => matches(&pattern[1..], &name[1..]),
+		(Some(p), Some(n)) if p == n => matches(&pattern[1..], &name[1..]),
+		_ => false,
+	}
+}
+
+// Answer files live next to the inputs, and aren't inputs themselves
+fn is_input(path: &Path) -> bool {
+	path.is_file() && path.extension().is_none_or(|ext| ext != "toml")
+}
+
+// The paths in `dir`, joined to it as given, so that listing the current
+// directory as "" gives bare file names
+fn list_dir(dir: &Path) -> Result<Vec<PathBuf>, Error> {
+	let shown = match dir.as_os_str().is_empty() {
+		true => Path::new("."),
+		false => dir,
+	};
+	fs::read_dir(shown)
+		.and_then(|entries| entries
+			.map(|e| e.map(|e| dir.join(e.file_name())))
+			.collect())
+		.map_err(|e| format!("cannot list {}: {}", shown.display(), e).into())
+}
+
+/// The input files named by `pattern`: every file in a directory, a single
+/// file, or the files matching wildcards in the last component of a path.
+pub fn expand(pattern: &str) -> Result<Vec<PathBuf>, Error> {
+	let path = Path::new(pattern);
+	let mut files = if path.is_dir() {
+		list_dir(path)?
+	} else if path.is_file() {
+		vec![path.to_path_buf()]
+	} else {
+		let name = path.file_name()
+			.and_then(|n| n.to_str())
+			.ok_or_else(|| format!("no input files match {}", pattern))?;
+		let name: Vec<char> = name.chars().collect();
+		let dir = path.parent().unwrap_or(Path::new(""));
+		list_dir(dir)?
+			.into_iter()
+			.filter(|p| p.file_name()
+				.and_then(|n| n.to_str())
+				.is_some_and(|n| matches(&name, &n.chars().collect::<Vec<_>>())))
+			.collect()
+	};
+	files.retain(|p| is_input(p));
+	files.sort();
+	match files.is_empty() {
+		true => Err(format!("no input files match {}", pattern).into()),
+		false => Ok(files),
+	}
+}
@@ -0,0 +1,791 @@
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process;
+use std::thread;
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+use common::{Error, Params, Source};
+use common::progress;
+use common::toml::Document;
+
+use answers::Known;
+
+mod alloc;
+mod answers;
+mod batch;
+mod bench;
+mod days;
+mod ddmin;
+mod detect;
+mod glob;
+mod minimize;
+mod pool;
+mod server;
+mod watch;
+
+const USAGE: &str = "\
+Usage:
+    aoc run [<day>] [--part N] [--input PATH|-] [--set NAME=VALUE]... [--json]
+    aoc run-all [--json] [--parallel] [--jobs N]
+    aoc batch <day> <dir|glob> [--jobs N] [--set NAME=VALUE]...
+    aoc verify [<day>]
+    aoc record [<day>] [--part N] [--input PATH] [--set NAME=VALUE]...
+    aoc bench [<day>] [--iters N] [--memory]
+    aoc gen <day> [--seed N] [--scale N]
+    aoc visual [<day>] [--part N] [--input PATH] [--set NAME=VALUE]...
+    aoc detect <path>...
+    aoc minimize <day> --input PATH|- (--crashes | --differs-from ANSWER)
+                 [--part N] [--set NAME=VALUE]... [--output PATH]
+    aoc serve [--port N]
+    aoc watch [<day>] [--input PATH] [--set NAME=VALUE]... [--interval MS]
+
+Without a day, run, record and visual detect it from the input, which then
+has to be given.
+
+Minimize removes lines and blocks from an input for as long as solving it
+still crashes, or gives an answer other than ANSWER, printing what's left.
+
+Puzzle parameters are read from the [dayN] tables of the file given with
+--config PATH, or else named by AOC_CONFIG, or else ./aoc.toml.
+
+With --timeout SECS, any part that takes longer to solve is stopped and
+reported as timed out, for every command. Minimize stops each attempt after
+10s unless given a timeout.
+
+Batch solves every input file in a directory, or matching `*` and `?` in
+the last component of a path, checking each against NAME.answers.toml when
+there is one. Answers are only ever checked against the file belonging to
+the input, which for a day's input.txt is answers.toml.
+
+Serve answers HTTP requests on localhost, port 8080 by default. POST the
+puzzle text to /day/N to get the answers and timings of both parts as JSON,
+or to /day/N/parse to get the parsed input.
+
+Watch solves the input again whenever it changes, checking every 500ms by
+default, and shows how the answers changed.
+
+With --memory, bench also counts the allocations, bytes allocated and peak
+live bytes of each phase.";
+
+enum Command {
+	Run { day: Option<u8>, part: Option<u8>, input: Option<Source>, params: Params, json: bool },
+	// `jobs` is set when solving the days in parallel
+	RunAll { json: bool, jobs: Option<usize> },
+	Batch { day: u8, pattern: String, jobs: usize, params: Params },
+	Verify { day: Option<u8> },
+	Record { day: Option<u8>, part: Option<u8>, input: Option<Source>, params: Params },
+	Bench { day: Option<u8>, iters: usize, memory: bool },
+	Gen { day: u8, seed: Option<u64>, scale: usize },
+	Visual { day: Option<u8>, part: u8, input: Option<Source>, params: Params },
+	Detect { paths: Vec<PathBuf> },
+	Minimize { input: Source, check: minimize::Check, output: Option<PathBuf> },
+	Serve { port: u16 },
+	Watch { day: Option<u8>, input: Option<Source>, params: Params, interval: u64 },
+}
+
+fn parse_num<T: std::str::FromStr>(what: &str, val: Option<String>) -> Result<T, String> {
+	let val = val.ok_or_else(|| format!("missing {}", what))?;
+	val.parse::<T>().map_err(|_| format!("invalid {}: {}", what, val))
+}
+
+fn check_day(day: u8) -> Result<(), String> {
+	match (1..=days::NUM_DAYS).contains(&day) {
+		true => Ok(()),
+		false => Err(format!("no such day: {}", day)),
+	}
+}
+
+struct Target {
+	day: Option<u8>,
+	part: Option<u8>,
+	input: Option<Source>,
+	params: Params,
+	json: bool,
+}
+
+// Parses `[<day>] [--part N] [--input PATH] [--set NAME=VALUE]... [--json]`,
+// where the input defaults to `AOC_INPUT` if set
+fn parse_target(args: impl Iterator<Item = String>) -> Result<Target, String> {
+	let mut args = args.peekable();
+	let day = match args.peek() {
+		Some(arg) if !arg.starts_with("--") => Some(parse_num("day", args.next())?),
+		_ => None,
+	};
+	let mut part = None;
+	let mut input = None;
+	let mut params = Params::new();
+	let mut json = false;
+	while let Some(arg) = args.next() {
+		match arg.as_str() {
+			"--part" => part = Some(parse_num("part", args.next())?),
+			"--input" => input = Some(args.next()
+				.map(Source::from_arg)
+				.ok_or("missing input path")?),
+			"--set" => params.set_arg(&args.next().ok_or("missing parameter")?)
+				.map_err(|e| e.to_string())?,
+			"--json" => json = true,
+			_ => return Err(format!("unexpected argument: {}", arg)),
+		}
+	}
+	if let Some(day) = day {
+		check_day(day)?;
+	}
+	if part.is_some_and(|p| p != 1 && p != 2) {
+		return Err("part must be 1 or 2".to_string());
+	}
+	let input = input.or_else(Source::from_env);
+	Ok(Target { day, part, input, params, json })
+}
+
+fn parse_args(mut args: impl Iterator<Item = String>) -> Result<Command, String> {
+	match args.next().as_deref() {
+		Some("run") => {
+			let Target { day, part, input, params, json } = parse_target(args)?;
+			Ok(Command::Run { day, part, input, params, json })
+		}
+		Some("batch") => {
+			let day = parse_num("day", args.next())?;
+			let pattern = args.next().ok_or("missing input directory or glob")?;
+			let mut jobs = default_jobs();
+			let mut params = Params::new();
+			while let Some(arg) = args.next() {
+				match arg.as_str() {
+					"--jobs" => jobs = parse_num("job count", args.next())?,
+					"--set" => params.set_arg(&args.next().ok_or("missing parameter")?)
+						.map_err(|e| e.to_string())?,
+					_ => return Err(format!("unexpected argument: {}", arg)),
+				}
+			}
+			check_day(day)?;
+			if jobs == 0 {
+				return Err("job count must be positive".to_string());
+			}
+			Ok(Command::Batch { day, pattern, jobs, params })
+		}
+		Some("record") => {
+			let Target { day, part, input, params, json } = parse_target(args)?;
+			if json {
+				return Err("unexpected argument: --json".to_string());
+			}
+			Ok(Command::Record { day, part, input, params })
+		}
+		Some("verify") => {
+			let day = args.next().map(|d| parse_num("day", Some(d))).transpose()?;
+			if let Some(day) = day {
+				check_day(day)?;
+			}
+			match args.next() {
+				None => Ok(Command::Verify { day }),
+				Some(arg) => Err(format!("unexpected argument: {}", arg)),
+			}
+		}
+		Some("run-all") => {
+			let mut json = false;
+			let mut jobs = None;
+			while let Some(arg) = args.next() {
+				match arg.as_str() {
+					"--json" => json = true,
+					"--parallel" => jobs = jobs.or(Some(default_jobs())),
+					"--jobs" => jobs = Some(parse_num("job count", args.next())?),
+					_ => return Err(format!("unexpected argument: {}", arg)),
+				}
+			}
+			if jobs == Some(0) {
+				return Err("job count must be positive".to_string());
+			}
+			Ok(Command::RunAll { json, jobs })
+		}
+		Some("bench") => {
+			let mut day = None;
+			let mut iters = 10;
+			let mut memory = false;
+			while let Some(arg) = args.next() {
+				match arg.as_str() {
+					"--iters" => iters = parse_num("iteration count", args.next())?,
+					"--memory" => memory = true,
+					_ if day.is_none() => day = Some(parse_num("day", Some(arg))?),
+					_ => return Err(format!("unexpected argument: {}", arg)),
+				}
+			}
+			if let Some(day) = day {
+				check_day(day)?;
+			}
+			if iters == 0 {
+				return Err("iteration count must be positive".to_string());
+			}
+			Ok(Command::Bench { day, iters, memory })
+		}
+		Some("gen") => {
+			let day = parse_num("day", args.next())?;
+			let mut seed = None;
+			let mut scale = 1;
+			while let Some(arg) = args.next() {
+				match arg.as_str() {
+					"--seed" => seed = Some(parse_num("seed", args.next())?),
+					"--scale" => scale = parse_num("scale", args.next())?,
+					_ => return Err(format!("unexpected argument: {}", arg)),
+				}
+			}
+			check_day(day)?;
+			if scale == 0 {
+				return Err("scale must be positive".to_string());
+			}
+			Ok(Command::Gen { day, seed, scale })
+		}
+		Some("visual") => {
+			let Target { day, part, input, params, json } = parse_target(args)?;
+			if json {
+				return Err("unexpected argument: --json".to_string());
+			}
+			// Commands are read from stdin while the simulation plays
+			if input == Some(Source::Stdin) {
+				return Err("cannot visualize input read from stdin".to_string());
+			}
+			Ok(Command::Visual { day, part: part.unwrap_or(1), input, params })
+		}
+		Some("minimize") => {
+			let day = parse_num("day", args.next())?;
+			let mut input = None;
+			let mut part = 1;
+			let mut sets = Vec::new();
+			let mut predicate = None;
+			let mut output = None;
+			while let Some(arg) = args.next() {
+				match arg.as_str() {
+					"--input" => input = Some(args.next()
+						.map(Source::from_arg)
+						.ok_or("missing input path")?),
+					"--part" => part = parse_num("part", args.next())?,
+					"--set" => sets.push(args.next().ok_or("missing parameter")?),
+					"--crashes" => predicate = Some(minimize::Predicate::Crashes),
+					"--differs-from" => predicate = Some(minimize::Predicate::DiffersFrom(
+						args.next().ok_or("missing answer")?)),
+					"--output" => output = Some(args.next()
+						.map(PathBuf::from)
+						.ok_or("missing output path")?),
+					_ => return Err(format!("unexpected argument: {}", arg)),
+				}
+			}
+			check_day(day)?;
+			if part != 1 && part != 2 {
+				return Err("part must be 1 or 2".to_string());
+			}
+			let input = input.or_else(Source::from_env).ok_or("missing input path")?;
+			let predicate = predicate.ok_or("missing --crashes or --differs-from")?;
+			let check = minimize::Check { day, part, sets, predicate, config: None };
+			Ok(Command::Minimize { input, check, output })
+		}
+		Some("serve") => {
+			let mut port = 8080;
+			while let Some(arg) = args.next() {
+				match arg.as_str() {
+					"--port" => port = parse_num("port", args.next())?,
+					_ => return Err(format!("unexpected argument: {}", arg)),
+				}
+			}
+			Ok(Command::Serve { port })
+		}
+		Some("watch") => {
+			let mut day = None;
+			let mut input = None;
+			let mut params = Params::new();
+			let mut interval = 500;
+			while let Some(arg) = args.next() {
+				match arg.as_str() {
+					"--input" => input = Some(args.next()
+						.map(Source::from_arg)
+						.ok_or("missing input path")?),
+					"--set" => params.set_arg(&args.next().ok_or("missing parameter")?)
+						.map_err(|e| e.to_string())?,
+					"--interval" => interval = parse_num("interval", args.next())?,
+					_ if day.is_none() && !arg.starts_with("--") =>
+						day = Some(parse_num("day", Some(arg))?),
+					_ => return Err(format!("unexpected argument: {}", arg)),
+				}
+			}
+			if let Some(day) = day {
+				check_day(day)?;
+			}
+			if input == Some(Source::Stdin) {
+				return Err("cannot watch input read from stdin".to_string());
+			}
+			if interval == 0 {
+				return Err("interval must be positive".to_string());
+			}
+			let input = input.or_else(Source::from_env);
+			Ok(Command::Watch { day, input, params, interval })
+		}
+		Some("detect") => {
+			let paths: Vec<_> = args.map(PathBuf::from).collect();
+			match paths.is_empty() {
+				true => Err("missing input path".to_string()),
+				false => Ok(Command::Detect { paths }),
+			}
+		}
+		Some(cmd) => Err(format!("unknown command: {}", cmd)),
+		None => Err("missing command".to_string()),
+	}
+}
+
+// Takes out the `--timeout SECS` option, which limits the time solving each
+// part may take, for every command
+fn take_timeout(args: &mut Vec<String>) -> Result<Option<Duration>, String> {
+	let Some(i) = args.iter().position(|a| a == "--timeout") else {
+		return Ok(None);
+	};
+	if i + 1 == args.len() {
+		return Err("missing timeout".to_string());
+	}
+	let secs: f64 = parse_num("timeout", Some(args.remove(i + 1)))?;
+	args.remove(i);
+	match secs > 0.0 && secs.is_finite() {
+		true => Ok(Some(Duration::from_secs_f64(secs))),
+		false => Err("timeout must be positive".to_string()),
+	}
+}
+
+// Takes out the `--config PATH` option, which applies to every command
+fn take_config(args: &mut Vec<String>) -> Result<Option<PathBuf>, String> {
+	let Some(i) = args.iter().position(|a| a == "--config") else {
+		return Ok(None);
+	};
+	if i + 1 == args.len() {
+		return Err("missing config path".to_string());
+	}
+	let path = args.remove(i + 1);
+	args.remove(i);
+	Ok(Some(PathBuf::from(path)))
+}
+
+// The parameters of a day from the config, with `overrides` on top
+fn day_params(config: &Document, day: u8, overrides: Params) -> Result<Params, Error> {
+	let mut params = Params::from_config(config, day)?;
+	params.extend(overrides);
+	Ok(params)
+}
+
+fn default_jobs() -> usize {
+	thread::available_parallelism().map_or(1, |n| n.get())
+}
+
+fn default_input(day: u8) -> PathBuf {
+	Path::new(&format!("day{}", day)).join("input.txt")
+}
+
+// Reads the input for a command, and works out its day from it when none
+// is given
+fn target(day: Option<u8>, input: Option<Source>) -> Result<(u8, Source, String), Error> {
+	if let Some(day) = day {
+		let src = input.unwrap_or_else(|| Source::File(default_input(day)));
+		let inp = src.read()?;
+		return Ok((day, src, inp));
+	}
+	let src = input.ok_or("missing day, and no input to detect it from")?;
+	let inp = src.read()?;
+	let best = detect::detect(&inp).into_iter().next()
+		.ok_or_else(|| format!("cannot tell which day {} is for", src))?;
+	eprintln!("{}: detected day {} with {:.0}% confidence", src, best.day,
+		best.confidence * 100.0);
+	Ok((best.day, src, inp))
+}
+
+// Runs a command on its day and input, reporting errors along with the day
+// once it's known
+fn with_target<F>(day: Option<u8>, input: Option<Source>, f: F) -> bool
+where
+	F: FnOnce(u8, Source, String) -> Result<(), Error>
+{
+	let res = target(day, input)
+		.map_err(|e| (day, e))
+		.and_then(|(day, src, inp)| f(day, src, inp).map_err(|e| (Some(day), e)));
+	match res {
+		Ok(()) => true,
+		Err((Some(day), e)) => {
+			eprintln!("Day {}: {}", day, e);
+			false
+		}
+		Err((None, e)) => {
+			eprintln!("error: {}", e);
+			false
+		}
+	}
+}
+
+// Prints the most likely day of each input, along with the runners-up
+fn detect(paths: &[PathBuf]) -> bool {
+	let mut ok = true;
+	for path in paths {
+		let src = Source::File(path.clone());
+		let candidates = match src.read() {
+			Ok(inp) => detect::detect(&inp),
+			Err(e) => {
+				eprintln!("error: {}", e);
+				ok = false;
+				continue;
+			}
+		};
+		let Some((best, rest)) = candidates.split_first() else {
+			println!("{}: unknown", src);
+			ok = false;
+			continue;
+		};
+		let mut line = format!("{}: day {} ({:.0}%)", src, best.day,
+			best.confidence * 100.0);
+		if !best.parses {
+			line.push_str(", but it doesn't parse");
+		}
+		let others: Vec<_> = rest.iter()
+			.filter(|c| c.confidence >= 0.01)
+			.map(|c| format!("day {} ({:.0}%)", c.day, c.confidence * 100.0))
+			.collect();
+		if !others.is_empty() {
+			line.push_str(&format!("; or {}", others.join(", ")));
+		}
+		println!("{}", line);
+	}
+	ok
+}
+
+// Shrinks a failing input, checking first that it fails at all
+fn minimize(src: &Source, check: &minimize::Check, output: Option<&Path>)
+	-> Result<(), Error>
+{
+	let inp = src.read()?;
+	if !check.holds(&inp)? {
+		return Err(match &check.predicate {
+			minimize::Predicate::Crashes => "solving the input doesn't crash".into(),
+			minimize::Predicate::DiffersFrom(ans) =>
+				format!("part {} doesn't give an answer other than {}", check.part, ans).into(),
+		});
+	}
+	let min = minimize::minimize(&inp, check)?;
+	eprintln!("Day {}: {} lines down to {}", check.day,
+		inp.lines().count(), min.lines().count());
+	match output {
+		Some(path) => fs::write(path, min)?,
+		None => print!("{}", min),
+	}
+	Ok(())
+}
+
+// Solves the given day, flagging answers that differ from the known ones.
+// In JSON mode each answer is printed as a record on its own line.
+fn run(day: u8, part: Option<u8>, src: &Source, inp: &str, params: &Params, json: bool)
+	-> Result<(), Error>
+{
+	// Input from stdin has no answers.toml next to it
+	let known = src.path().map(Known::load).transpose()?;
+	let mut mismatch = false;
+	let answers = days::run(day, inp, part, params)?;
+	if !json {
+		println!("Day {}", day);
+	}
+	for ans in answers.iter().filter(|a| a.timed_out.is_none()) {
+		let expected = known.as_ref().and_then(|k| k.check(ans.part, &ans.text));
+		mismatch |= expected.is_some();
+		match (json, expected) {
+			(true, _) => println!("{}",
+				common::json_record(day, ans.part, ans.json.clone(), ans.duration)),
+			(false, None) => println!("Part {}: {}", ans.part, ans.text),
+			(false, Some(expected)) => println!("Part {}: {} (expected {})",
+				ans.part, ans.text, expected),
+		}
+	}
+	days::check_timeouts(&answers)?;
+	match mismatch {
+		true => Err("answers differ from the recorded ones".into()),
+		false => Ok(()),
+	}
+}
+
+// Solves the given day and stores its answers as the known good ones
+fn record(day: u8, part: Option<u8>, src: &Source, inp: &str, params: &Params)
+	-> Result<(), Error>
+{
+	let path = src.path().ok_or("cannot record answers for input from stdin")?;
+	let mut known = Known::load(path)?;
+	let answers = days::run(day, inp, part, params)?;
+	days::check_timeouts(&answers)?;
+	for ans in answers {
+		println!("Day {}: recorded part {}: {}", day, ans.part, ans.text);
+		known.record(ans.part, &ans.text);
+	}
+	known.save()
+}
+
+// Checks the given day, or every day with recorded answers, against the
+// known answers
+fn verify(only: Option<u8>, config: &Document) -> bool {
+	let mut ok = true;
+	let days = match only {
+		Some(day) => day..=day,
+		None => 1..=days::NUM_DAYS,
+	};
+	for day in days {
+		let path = default_input(day);
+		let res = Known::load(&path).and_then(|known| {
+			if !known.exists() || !path.exists() {
+				return Ok(None);
+			}
+			let inp = Source::File(path.clone()).read()?;
+			let params = day_params(config, day, Params::new())?;
+			let answers = days::run(day, &inp, None, &params)?;
+			days::check_timeouts(&answers)?;
+			let regressed: Vec<_> = answers.into_iter()
+				.filter_map(|ans| known.check(ans.part, &ans.text)
+					.map(|exp| (ans.part, ans.text, exp.to_string())))
+				.collect();
+			Ok(Some(regressed))
+		});
+
+		match res {
+			Ok(None) if only.is_none() => (),
+			Ok(None) => {
+				eprintln!("Day {}: no input or recorded answers", day);
+				ok = false;
+			}
+			Ok(Some(regressed)) if regressed.is_empty() =>
+				println!("Day {}: ok", day),
+			Ok(Some(regressed)) => {
+				for (part, answer, expected) in regressed {
+					println!("Day {}: part {} regressed: got {}, expected {}",
+						day, part, answer, expected);
+				}
+				ok = false;
+			}
+			Err(e) => {
+				eprintln!("Day {}: {}", day, e);
+				ok = false;
+			}
+		}
+	}
+	ok
+}
+
+fn run_all(json: bool, config: &Document) -> bool {
+	let mut ok = true;
+	for day in 1..=days::NUM_DAYS {
+		let path = default_input(day);
+		if !path.exists() {
+			eprintln!("Day {}: skipped, {} not found", day, path.display());
+			continue;
+		}
+		let src = Source::File(path);
+		let res = day_params(config, day, Params::new())
+			.and_then(|params| run(day, None, &src, &src.read()?, &params, json));
+		if let Err(e) = res {
+			eprintln!("Day {}: {}", day, e);
+			ok = false;
+		}
+	}
+	ok
+}
+
+// Solves every day with an input file on a pool of threads, printing a
+// summary once all are done
+fn run_all_parallel(json: bool, jobs: usize, config: &Document) -> bool {
+	let mut ok = true;
+	let mut inputs = Vec::new();
+	for day in 1..=days::NUM_DAYS {
+		let path = default_input(day);
+		if !path.exists() {
+			eprintln!("Day {}: skipped, {} not found", day, path.display());
+			continue;
+		}
+		match day_params(config, day, Params::new()) {
+			Ok(params) => inputs.push((day, Source::File(path), params)),
+			Err(e) => {
+				eprintln!("Day {}: {}", day, e);
+				ok = false;
+			}
+		}
+	}
+
+	let start = Instant::now();
+	let reports = pool::run_all(inputs, jobs);
+	let total = start.elapsed();
+	if !json {
+		pool::print_summary(&reports, total);
+		return ok && reports.iter().all(pool::Report::ok);
+	}
+
+	// Whatever was computed is printed, even alongside an error
+	for report in reports {
+		let (answers, error) = match report.outcome {
+			pool::Outcome::Solved(answers) => (answers, None),
+			pool::Outcome::Wrong(answers, _) =>
+				(answers, Some("answers differ from the recorded ones".to_string())),
+			pool::Outcome::Failed(e) => (Vec::new(), Some(e)),
+			pool::Outcome::Panicked(answers, msg) => (answers, Some(msg)),
+		};
+		for ans in answers {
+			println!("{}", common::json_record(report.day, ans.part,
+				ans.json, ans.duration));
+		}
+		if let Some(e) = error {
+			eprintln!("Day {}: {}", report.day, e);
+			ok = false;
+		}
+	}
+	ok
+}
+
+// Solves the given day for every input file named by `pattern`, printing a
+// table of the answers
+fn batch(day: u8, pattern: &str, jobs: usize, params: &Params) -> Result<bool, Error> {
+	let files = glob::expand(pattern)?;
+	let inputs = files.iter()
+		.map(|f| (day, Source::File(f.clone()), params.clone()))
+		.collect();
+
+	let start = Instant::now();
+	let reports = pool::run_all(inputs, jobs);
+	let total = start.elapsed();
+	batch::print_table(&files, &reports, total);
+	Ok(reports.iter().all(pool::Report::ok))
+}
+
+// Benchmarks the given day, or every day with an input file
+fn bench(only: Option<u8>, iters: usize, memory: bool, config: &Document) -> bool {
+	if memory {
+		alloc::enable();
+	}
+	let mut ok = true;
+	let mut rows = Vec::new();
+	let days = match only {
+		Some(day) => day..=day,
+		None => 1..=days::NUM_DAYS,
+	};
+	for day in days {
+		let path = default_input(day);
+		if only.is_none() && !path.exists() {
+			eprintln!("Day {}: skipped, {} not found", day, path.display());
+			continue;
+		}
+		let timings = Source::File(path)
+			.read()
+			.and_then(|inp| {
+				let params = day_params(config, day, Params::new())?;
+				days::bench(day, &inp, iters, &params, memory)
+			});
+		match timings {
+			Ok(t) => rows.push((day, t)),
+			Err(e) => {
+				eprintln!("Day {}: {}", day, e);
+				ok = false;
+			}
+		}
+	}
+	if !rows.is_empty() {
+		bench::print_table(&rows);
+	}
+	ok
+}
+
+// Prints a random input, reporting the seed used so it can be reproduced
+fn gen(day: u8, seed: Option<u64>, scale: usize) -> bool {
+	let seed = seed.unwrap_or_else(|| SystemTime::now()
+		.duration_since(UNIX_EPOCH)
+		.map_or(0, |d| d.as_nanos() as u64));
+	eprintln!("Day {}: seed {}", day, seed);
+	match days::gen(day, seed, scale) {
+		Ok(inp) => {
+			print!("{}", inp);
+			true
+		}
+		Err(e) => {
+			eprintln!("Day {}: {}", day, e);
+			false
+		}
+	}
+}
+
+fn main() {
+	let mut args: Vec<_> = env::args().skip(1).collect();
+	let (mut cmd, config) = match take_config(&mut args)
+		.and_then(|config| {
+			progress::set_budget(take_timeout(&mut args)?);
+			Ok((parse_args(args.into_iter())?, config))
+		})
+	{
+		Ok(res) => res,
+		Err(e) => {
+			eprintln!("error: {}\n\n{}", e, USAGE);
+			process::exit(2);
+		}
+	};
+	// The solvers minimize starts have to read the same config
+	if let Command::Minimize { check, .. } = &mut cmd {
+		check.config = config.clone();
+	}
+	let config = common::params::load_config(config.as_deref())
+		.unwrap_or_else(|e| {
+			eprintln!("error: {}", e);
+			process::exit(1);
+		});
+
+	let ok = match cmd {
+		Command::Run { day, part, input, params, json } =>
+			with_target(day, input, |day, src, inp| {
+				progress::set_visible(true);
+				let params = day_params(&config, day, params)?;
+				run(day, part, &src, &inp, &params, json)
+			}),
+		Command::RunAll { json, jobs: None } => run_all(json, &config),
+		Command::RunAll { json, jobs: Some(jobs) } =>
+			run_all_parallel(json, jobs, &config),
+		Command::Batch { day, pattern, jobs, params } =>
+			day_params(&config, day, params)
+				.and_then(|params| batch(day, &pattern, jobs, &params))
+				.unwrap_or_else(|e| {
+					eprintln!("Day {}: {}", day, e);
+					false
+				}),
+		Command::Verify { day } => verify(day, &config),
+		Command::Record { day, part, input, params } =>
+			with_target(day, input, |day, src, inp| {
+				let params = day_params(&config, day, params)?;
+				record(day, part, &src, &inp, &params)
+			}),
+		Command::Bench { day, iters, memory } => bench(day, iters, memory, &config),
+		Command::Gen { day, seed, scale } => gen(day, seed, scale),
+		Command::Visual { day, part, input, params } =>
+			with_target(day, input, |day, _, inp| {
+				let params = day_params(&config, day, params)?;
+				days::visualize(day, &inp, part, &params)
+			}),
+		Command::Detect { paths } => detect(&paths),
+		Command::Minimize { input, check, output } =>
+			minimize(&input, &check, output.as_deref())
+				.map_err(|e| eprintln!("Day {}: {}", check.day, e))
+				.is_ok(),
+		// The input is only read up front when its day has to be detected,
+		// since watch reports fixes to it on its own
+		Command::Watch { day: Some(day), input, params, interval } => {
+			progress::set_visible(true);
+			let path = input.as_ref()
+				.and_then(Source::path)
+				.map_or_else(|| default_input(day), Path::to_path_buf);
+			day_params(&config, day, params)
+				.and_then(|params| watch::run(day, &path, &params,
+					Duration::from_millis(interval)))
+				.map_err(|e| eprintln!("Day {}: {}", day, e))
+				.is_ok()
+		}
+		Command::Watch { day: None, input, params, interval } =>
+			with_target(None, input, |day, src, _| {
+				progress::set_visible(true);
+				let params = day_params(&config, day, params)?;
+				let path = src.path().ok_or("cannot watch input read from stdin")?;
+				watch::run(day, path, &params, Duration::from_millis(interval))
+			}),
+		Command::Serve { port } => server::run(port, config)
+			.map_err(|e| eprintln!("error: {}", e))
+			.is_ok(),
+	};
+
+	if !ok {
+		process::exit(1);
+	}
+}
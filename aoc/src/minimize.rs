@@ -0,0 +1,173 @@
+// Cuts a failing input down to a small one that still fails, by delta
+// debugging over its lines and blocks. Each candidate is solved in a child
+// process, so that even a stack overflow can be told apart from a clean
+// exit.
+
+use std::env;
+use std::io::Write;
+use std::path::PathBuf;
+use std::process::{Command, Stdio};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use common::Error;
+use common::progress;
+
+use crate::ddmin::ddmin;
+
+// How long each attempt may take when no timeout is given
+const DEFAULT_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// What makes an input interesting enough to keep.
+pub enum Predicate {
+	/// Solving it panics or otherwise crashes.
+	Crashes,
+	/// The answer to a part is something other than the given one.
+	DiffersFrom(String),
+}
+
+/// How to solve a candidate input.
+pub struct Check {
+	pub day: u8,
+	pub part: u8,
+	// `--set NAME=VALUE` arguments passed on to the solver
+	pub sets: Vec<String>,
+	pub predicate: Predicate,
+	// The `--config` given to the minimizer, if any
+	pub config: Option<PathBuf>,
+}
+
+enum Outcome {
+	Answer(String),
+	Failed,
+	Crashed,
+	TimedOut,
+}
+
+impl Check {
+	fn solve(&self, inp: &str) -> Result<Outcome, Error> {
+		// The solver stops itself once out of time, but gets killed if it
+		// doesn't check in the meantime
+		let timeout = progress::budget().unwrap_or(DEFAULT_TIMEOUT);
+		let mut cmd = Command::new(env::current_exe()?);
+		cmd.args(["run", &self.day.to_string(), "--part", &self.part.to_string()]);
+		cmd.args(["--timeout", &timeout.as_secs_f64().to_string()]);
+		if let Some(config) = &self.config {
+			cmd.arg("--config").arg(config);
+		}
+		for set in &self.sets {
+			cmd.args(["--set", set]);
+		}
+		let mut child = cmd.args(["--input", "-"])
+			.stdin(Stdio::piped())
+			.stdout(Stdio::piped())
+			.stderr(Stdio::null())
+			.spawn()?;
+
+		// The input is written from another thread, in case the solver exits
+		// without reading it all
+		let mut stdin = child.stdin.take().unwrap();
+		let inp = inp.to_string();
+		let writer = thread::spawn(move || {
+			let _ = stdin.write_all(inp.as_bytes());
+		});
+
+		let start = Instant::now();
+		let status = loop {
+			if let Some(status) = child.try_wait()? {
+				break status;
+			}
+			if start.elapsed() > timeout + Duration::from_secs(1) {
+				child.kill()?;
+				child.wait()?;
+				let _ = writer.join();
+				return Ok(Outcome::TimedOut);
+			}
+			thread::sleep(Duration::from_millis(5));
+		};
+		let _ = writer.join();
+		let out = child.wait_with_output()?;
+
+		// A parse error, a timeout or a mismatch with the recorded answers
+		// exits with 1, while panics exit with 101 and stack overflows are
+		// killed by a signal
+		Ok(match status.code() {
+			Some(0) => {
+				let stdout = String::from_utf8_lossy(&out.stdout);
+				let prefix = format!("Part {}: ", self.part);
+				let answer = stdout.split_once(&prefix).map_or("", |(_, ans)| ans);
+				Outcome::Answer(answer.trim_end().to_string())
+			}
+			Some(1) | Some(2) => Outcome::Failed,
+			_ => Outcome::Crashed,
+		})
+	}
+
+	pub fn holds(&self, inp: &str) -> Result<bool, Error> {
+		Ok(match (&self.predicate, self.solve(inp)?) {
+			(Predicate::Crashes, Outcome::Crashed) => true,
+			(Predicate::DiffersFrom(expected), Outcome::Answer(ans)) => ans != *expected,
+			_ => false,
+		})
+	}
+}
+
+type Block = Vec<String>;
+
+fn join(blocks: &[Block]) -> String {
+	let mut out = blocks.iter()
+		.map(|b| b.join("\n"))
+		.collect::<Vec<_>>()
+		.join("\n\n");
+	out.push('\n');
+	out
+}
+
+// Minimizes the lines of block `i`, except for the last `keep` of them,
+// with the other blocks as they are
+fn lines_of(blocks: &mut [Block], i: usize, keep: usize, check: &Check)
+	-> Result<(), Error>
+{
+	let split = blocks[i].len().saturating_sub(keep);
+	let fixed = blocks[i][split..].to_vec();
+	let lines = blocks[i][..split].to_vec();
+	let mut candidate = blocks.to_vec();
+	let lines = ddmin(lines, |lines| {
+		candidate[i] = [lines, &fixed].concat();
+		check.holds(&join(&candidate))
+	})?;
+	blocks[i] = [lines, fixed].concat();
+	Ok(())
+}
+
+/// Shrinks `inp` while `check` still holds, following the structure of the
+/// day's input: whole elves, monkeys and packet pairs go first, and day 5
+/// keeps both its drawing and its moves, as well as the row of column
+/// numbers.
+pub fn minimize(inp: &str, check: &Check) -> Result<String, Error> {
+	let mut blocks: Vec<Block> = common::blocks(inp)
+		.map(|(_, b)| b.lines().map(str::to_string).collect())
+		.collect();
+	match check.day {
+		1 | 11 | 13 => {
+			blocks = ddmin(blocks, |blocks| check.holds(&join(blocks)))?;
+			// Calories can go one at a time, unlike the lines of a monkey or
+			// a pair of packets
+			if check.day == 1 {
+				for i in 0..blocks.len() {
+					lines_of(&mut blocks, i, 0, check)?;
+				}
+			}
+		}
+		5 if blocks.len() == 2 => {
+			lines_of(&mut blocks, 1, 0, check)?;
+			lines_of(&mut blocks, 0, 1, check)?;
+		}
+		_ => {
+			let lines = inp.lines().map(str::to_string).collect();
+			blocks = vec![lines];
+			lines_of(&mut blocks, 0, 0, check)?;
+		}
+	}
+	Ok(join(&blocks))
+}
@@ -0,0 +1,161 @@
+use std::panic;
+use std::sync::Mutex;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use common::{Json, Params, Source, panic_message};
+
+use crate::answers::Known;
+use crate::bench::Human;
+use crate::days::{self, Answers};
+
+pub enum Outcome {
+	Solved(Answers),
+	// Answers that differ from the recorded ones, as (part, expected)
+	Wrong(Answers, Vec<(u8, String)>),
+	Failed(String),
+	// The answers of the parts that didn't panic, with what went wrong
+	Panicked(Answers, String),
+}
+
+pub struct Report {
+	pub day: u8,
+	pub outcome: Outcome,
+	pub wall: Duration,
+}
+
+impl Report {
+	pub fn ok(&self) -> bool {
+		matches!(self.outcome, Outcome::Solved(_))
+	}
+}
+
+fn solve(day: u8, src: &Source, params: &Params) -> Outcome {
+	let res = src.read().and_then(|inp| {
+		let known = src.path().map(Known::load).transpose()?;
+		Ok((inp, known))
+	});
+	let (inp, known) = match res {
+		Ok(res) => res,
+		Err(e) => return Outcome::Failed(e.to_string()),
+	};
+
+	// Parts are caught one at a time, so only a panic while parsing loses
+	// both
+	let parts = match panic::catch_unwind(|| days::run_caught(day, &inp, params)) {
+		Ok(Ok(parts)) => parts,
+		Ok(Err(e)) => return Outcome::Failed(e.to_string()),
+		Err(payload) => return Outcome::Panicked(Answers::new(),
+			format!("panicked: {}", panic_message(&*payload))),
+	};
+	let mut answers = Answers::new();
+	let mut panics = Vec::new();
+	for part in parts {
+		match part {
+			Ok(ans) => answers.push(ans),
+			Err((part, msg)) => panics.push(format!("part {} panicked: {}", part, msg)),
+		}
+	}
+	if !panics.is_empty() {
+		return Outcome::Panicked(answers, panics.join(", "));
+	}
+	if let Err(e) = days::check_timeouts(&answers) {
+		return Outcome::Failed(e.to_string());
+	}
+	let wrong: Vec<_> = answers.iter()
+		.filter_map(|ans| known.as_ref()
+			.and_then(|k| k.check(ans.part, &ans.text))
+			.map(|exp| (ans.part, exp.to_string())))
+		.collect();
+	match wrong.is_empty() {
+		true => Outcome::Solved(answers),
+		false => Outcome::Wrong(answers, wrong),
+	}
+}
+
+// Solves every given input on `jobs` threads, returning the reports in the
+// order of the inputs. A panicking day is reported rather than taking the
+// others down.
+pub fn run_all(inputs: Vec<(u8, Source, Params)>, jobs: usize) -> Vec<Report> {
+	let next = AtomicUsize::new(0);
+	let reports = Mutex::new(Vec::with_capacity(inputs.len()));
+
+	// The default hook would print each panic in the middle of the other
+	// days' output, so it's silenced while the pool runs
+	let hook = panic::take_hook();
+	panic::set_hook(Box::new(|_| ()));
+	thread::scope(|s| {
+		for _ in 0..jobs.clamp(1, inputs.len().max(1)) {
+			s.spawn(|| loop {
+				let i = next.fetch_add(1, Ordering::Relaxed);
+				let Some((day, src, params)) = inputs.get(i) else {
+					break;
+				};
+				let start = Instant::now();
+				let outcome = solve(*day, src, params);
+				let wall = start.elapsed();
+				reports.lock().unwrap().push((i, Report { day: *day, outcome, wall }));
+			});
+		}
+	});
+	panic::set_hook(hook);
+
+	let mut reports = reports.into_inner().unwrap();
+	reports.sort_by_key(|(i, _)| *i);
+	reports.into_iter().map(|(_, r)| r).collect()
+}
+
+// Multi-line answers (day 10's screen) are summarized by their decoded text
+// when there is one
+fn brief(ans: &Answers, part: u8) -> String {
+	let Some(ans) = ans.iter().find(|a| a.part == part) else {
+		return "-".to_string();
+	};
+	if !ans.text.contains('\n') {
+		return ans.text.clone();
+	}
+	match &ans.json {
+		Json::Object(fields) => fields.iter()
+			.find_map(|(k, v)| match (k.as_str(), v) {
+				("text", Json::String(s)) => Some(s.clone()),
+				_ => None,
+			})
+			.unwrap_or_else(|| format!("<{} lines>", ans.text.lines().count())),
+		_ => format!("<{} lines>", ans.text.lines().count()),
+	}
+}
+
+impl Report {
+	// The answers to both parts in brief, and how solving went, on one line
+	pub fn summary(&self) -> (String, String, String) {
+		let (p1, p2, status) = match &self.outcome {
+			Outcome::Solved(ans) => (brief(ans, 1), brief(ans, 2), "ok".to_string()),
+			Outcome::Wrong(ans, wrong) => {
+				let status = wrong.iter()
+					.map(|(part, exp)| format!("part {} expected {}", part, exp))
+					.collect::<Vec<_>>()
+					.join(", ");
+				(brief(ans, 1), brief(ans, 2), status)
+			}
+			Outcome::Failed(e) => ("-".into(), "-".into(), format!("error: {}", e)),
+			Outcome::Panicked(ans, msg) => (brief(ans, 1), brief(ans, 2), msg.clone()),
+		};
+		// Errors such as parse snippets span several lines; keep the
+		// first so the table stays aligned
+		let status = status.lines().next().unwrap_or("").to_string();
+		(p1, p2, status)
+	}
+}
+
+pub fn print_summary(reports: &[Report], total: Duration) {
+	println!("{:>3}  {:<16}  {:<16}  {:>10}  Status",
+		"Day", "Part 1", "Part 2", "Time");
+	for r in reports {
+		let (p1, p2, status) = r.summary();
+		println!("{:>3}  {:<16}  {:<16}  {:>10}  {}",
+			r.day, p1, p2, Human(r.wall), status);
+	}
+	let failed = reports.iter().filter(|r| !r.ok()).count();
+	println!("{} days in {}, {} failed", reports.len(), Human(total), failed);
+}
@@ -0,0 +1,201 @@
+// A small HTTP server on localhost, so that other tools can solve inputs
+// without running the binary. Each connection gets its own thread and a
+// single request, answered with JSON.
+
+use std::io::{BufRead, BufReader, Read, Write};
+use std::net::{Ipv4Addr, TcpListener, TcpStream};
+use std::panic;
+use std::sync::Arc;
+use std::thread;
+use std::time::Duration;
+
+use common::{Error, Json, Params, ToJson, panic_message};
+use common::input;
+use common::toml::Document;
+
+use crate::days::{self, NUM_DAYS};
+
+// Inputs are a few dozen KiB, so anything much larger is a mistake
+const MAX_BODY: usize = 16 << 20;
+// How long a client may take to send its request
+const READ_TIMEOUT: Duration = Duration::from_secs(30);
+
+struct Request {
+	method: String,
+	path: String,
+	body: Vec<u8>,
+}
+
+struct Response {
+	status: u16,
+	body: Json,
+}
+
+impl Response {
+	fn ok(body: Json) -> Self {
+		Self { status: 200, body }
+	}
+
+	fn error(status: u16, msg: impl ToString) -> Self {
+		Self { status, body: Json::object([("error", msg.to_string().to_json())]) }
+	}
+}
+
+fn reason(status: u16) -> &'static str {
+	match status {
+		200 => "OK",
+		400 => "Bad Request",
+		404 => "Not Found",
+		405 => "Method Not Allowed",
+		413 => "Payload Too Large",
+		422 => "Unprocessable Entity",
+		_ => "Internal Server Error",
+	}
+}
+
+// Reads the request line, the headers and as much body as they announce.
+// Errors come back as the response to send.
+fn read_request(stream: &TcpStream) -> Result<Request, Response> {
+	let bad = |e: std::io::Error| Response::error(400, e);
+	let mut reader = BufReader::new(stream);
+	let mut line = String::new();
+	reader.read_line(&mut line).map_err(bad)?;
+	let mut words = line.split_whitespace();
+	let (Some(method), Some(target)) = (words.next(), words.next()) else {
+		return Err(Response::error(400, "malformed request line"));
+	};
+	let method = method.to_string();
+	// Query strings are ignored
+	let path = target.split('?').next().unwrap_or("").to_string();
+
+	let mut len = 0;
+	loop {
+		line.clear();
+		reader.read_line(&mut line).map_err(bad)?;
+		let header = line.trim_end();
+		if header.is_empty() {
+			break;
+		}
+		let Some((name, value)) = header.split_once(':') else {
+			return Err(Response::error(400, "malformed header"));
+		};
+		if name.eq_ignore_ascii_case("content-length") {
+			len = value.trim().parse()
+				.map_err(|_| Response::error(400, "invalid content length"))?;
+		} else if name.eq_ignore_ascii_case("transfer-encoding") {
+			return Err(Response::error(400, "chunked bodies are not supported"));
+		}
+	}
+	if len > MAX_BODY {
+		return Err(Response::error(413, format!("body over {} bytes", MAX_BODY)));
+	}
+	let mut body = vec![0; len];
+	reader.read_exact(&mut body).map_err(bad)?;
+	Ok(Request { method, path, body })
+}
+
+// The solved parts as in JSON output mode, each with an error instead of an
+// answer if it ran out of time
+fn answers(day: u8, inp: &str, params: &Params) -> Result<Json, Error> {
+	let answers = days::run(day, inp, None, params)?
+		.into_iter()
+		.map(|ans| {
+			let mut record = common::json_record(day, ans.part, ans.json, ans.duration);
+			if let (Json::Object(fields), Some(e)) = (&mut record, ans.timed_out) {
+				fields.push(("error".to_string(), e.to_string().to_json()));
+			}
+			record
+		})
+		.collect();
+	Ok(Json::object([
+		("day", day.to_json()),
+		("answers", Json::Array(answers)),
+	]))
+}
+
+fn parsed(day: u8, inp: &str, params: &Params) -> Result<Json, Error> {
+	Ok(Json::object([
+		("day", day.to_json()),
+		("input", days::parsed(day, inp, params)?),
+	]))
+}
+
+type Endpoint = fn(u8, &str, &Params) -> Result<Json, Error>;
+
+fn handle(req: Request, config: &Document) -> Response {
+	let segments: Vec<_> = req.path.trim_matches('/').split('/').collect();
+	let (day, solve): (_, Endpoint) = match segments[..] {
+		["day", day] => (day, answers),
+		["day", day, "parse"] => (day, parsed),
+		_ => return Response::error(404, format!("no such endpoint: {}", req.path)),
+	};
+	let day = match day.parse::<u8>() {
+		Ok(day) if (1..=NUM_DAYS).contains(&day) => day,
+		_ => return Response::error(404, format!("no such day: {}", day)),
+	};
+	if req.method != "POST" {
+		return Response::error(405, "only POST is allowed");
+	}
+	let Ok(raw) = String::from_utf8(req.body) else {
+		return Response::error(400, "input is not valid UTF-8");
+	};
+	let (inp, fixes) = input::normalize(&raw);
+	let params = match Params::from_config(config, day) {
+		Ok(params) => params,
+		Err(e) => return Response::error(500, e),
+	};
+
+	// The input is the client's to fix, whether it doesn't parse or makes
+	// the solver panic
+	let mut res = match panic::catch_unwind(|| solve(day, &inp, &params)) {
+		Ok(Ok(body)) => Response::ok(body),
+		Ok(Err(e)) => Response::error(422, e),
+		Err(payload) => Response::error(422,
+			format!("panicked: {}", panic_message(&*payload))),
+	};
+	// Fixes made to the input, which the command line prints as warnings
+	if let Json::Object(fields) = &mut res.body {
+		fields.push(("warnings".to_string(), fixes.to_json()));
+	}
+	res
+}
+
+fn serve(mut stream: TcpStream, config: &Document) {
+	let _ = stream.set_read_timeout(Some(READ_TIMEOUT));
+	let res = match read_request(&stream) {
+		Ok(req) => handle(req, config),
+		Err(res) => res,
+	};
+	let body = format!("{}\n", res.body);
+	let head = format!("HTTP/1.1 {} {}\r\nContent-Type: application/json\r\n\
+		Content-Length: {}\r\nConnection: close\r\n\r\n",
+		res.status, reason(res.status), body.len());
+	let _ = stream.write_all(head.as_bytes())
+		.and_then(|_| stream.write_all(body.as_bytes()));
+}
+
+/// Answers requests on the given port of localhost until killed, with the
+/// parameters of each day taken from `config`.
+pub fn run(port: u16, config: Document) -> Result<(), Error> {
+	let listener = TcpListener::bind((Ipv4Addr::LOCALHOST, port))
+		.map_err(|e| format!("cannot listen on port {}: {}", port, e))?;
+	eprintln!("Listening on http://{}", listener.local_addr()?);
+
+	let config = Arc::new(config);
+	for stream in listener.incoming() {
+		let stream = match stream {
+			Ok(stream) => stream,
+			Err(e) => {
+				eprintln!("error: {}", e);
+				continue;
+			}
+		};
+		let config = Arc::clone(&config);
+		let spawned = thread::Builder::new()
+			.spawn(move || serve(stream, &config));
+		if let Err(e) = spawned {
+			eprintln!("error: {}", e);
+		}
+	}
+	Ok(())
+}
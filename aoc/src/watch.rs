@@ -0,0 +1,89 @@
+// Solves an input again every time it changes, checking by reading it at a
+// fixed interval rather than through file system notifications, which
+// differ on every platform
+
+use std::panic;
+use std::path::Path;
+use std::thread;
+use std::time::Duration;
+
+use common::{Error, Params, Source, panic_message};
+use common::input;
+
+use crate::bench::Human;
+use crate::days::{self, Answers};
+
+// The answers of the last successful run, as (part, text)
+type Previous = Vec<(u8, String)>;
+
+fn solve(day: u8, inp: &str, params: &Params) -> Result<Answers, String> {
+	match panic::catch_unwind(|| days::run(day, inp, None, params)) {
+		Ok(Ok(answers)) => Ok(answers),
+		Ok(Err(e)) => Err(e.to_string()),
+		Err(payload) => Err(format!("panicked: {}", panic_message(&*payload))),
+	}
+}
+
+// Prints each answer next to the previous one when it changed, and keeps
+// the new ones unless solving failed
+fn report(day: u8, inp: &str, params: &Params, prev: &mut Previous) {
+	let answers = match solve(day, inp, params) {
+		Ok(answers) => answers,
+		Err(e) => {
+			println!("Day {}: {}", day, e);
+			return;
+		}
+	};
+	for ans in &answers {
+		if ans.timed_out.is_some() {
+			println!("Part {}: {}", ans.part, ans.text);
+			continue;
+		}
+		let change = match prev.iter().find(|(part, _)| *part == ans.part) {
+			Some((_, old)) if *old == ans.text => " (unchanged)".to_string(),
+			Some((_, old)) => format!(" (was {})", old),
+			None => String::new(),
+		};
+		println!("Part {}: {}{} in {}", ans.part, ans.text, change, Human(ans.duration));
+	}
+	// A part that timed out keeps its last answer to compare against
+	for ans in answers.into_iter().filter(|a| a.timed_out.is_none()) {
+		prev.retain(|(part, _)| *part != ans.part);
+		prev.push((ans.part, ans.text));
+	}
+}
+
+/// Solves `path` for the given day now and whenever its contents change,
+/// until killed.
+pub fn run(day: u8, path: &Path, params: &Params, interval: Duration) -> Result<(), Error> {
+	let src = Source::File(path.to_path_buf());
+	let mut prev = Previous::new();
+	let mut last = None;
+	let mut missing = false;
+	eprintln!("Watching {} for day {}", src, day);
+	loop {
+		// Editors that save by replacing the file leave it missing for a
+		// moment, which is only worth saying once. Changes are spotted in
+		// the raw contents, so that fixing them up only happens, and is only
+		// warned about, once per change.
+		match src.read_raw() {
+			Ok(raw) if last.as_ref() != Some(&raw) => {
+				println!("--- {}", src);
+				let (inp, fixes) = input::normalize(&raw);
+				for fix in fixes {
+					println!("warning: {}", fix);
+				}
+				report(day, &inp, params, &mut prev);
+				last = Some(raw);
+				missing = false;
+			}
+			Ok(_) => missing = false,
+			Err(e) if !missing => {
+				println!("--- {}", e);
+				missing = true;
+			}
+			Err(_) => (),
+		}
+		thread::sleep(interval);
+	}
+}
@@ -0,0 +1,71 @@
+#[path = "../src/ddmin.rs"]
+mod ddmin;
+
+use common::Error;
+use ddmin::ddmin;
+
+fn minimize(items: Vec<u32>, keep: impl Fn(&[u32]) -> bool) -> Vec<u32> {
+	let min = ddmin(items, |v| Ok(keep(v))).unwrap();
+	assert!(keep(&min), "{:?}", min);
+	// 1-minimal: no single item can go
+	for i in 0..min.len() {
+		let mut less = min.clone();
+		less.remove(i);
+		assert!(less.is_empty() || !keep(&less), "{:?} is not 1-minimal", min);
+	}
+	min
+}
+
+#[test]
+fn needed_items_are_kept() {
+	let keep = |v: &[u32]| v.contains(&3) && v.contains(&71);
+	assert_eq!(minimize((0..100).collect(), keep), [3, 71]);
+	// Both at the same end, then split across chunks of every size
+	assert_eq!(minimize((0..100).rev().collect(), |v| v.contains(&0) && v.contains(&1)), [1, 0]);
+	assert_eq!(minimize((0..37).collect(), |v| [5, 6, 20, 36].iter().all(|x| v.contains(x))), [5, 6, 20, 36]);
+}
+
+#[test]
+fn order_is_kept() {
+	let keep = |v: &[u32]| {
+		let two = v.iter().position(|&x| x == 2);
+		let five = v.iter().position(|&x| x == 5);
+		matches!((two, five), (Some(a), Some(b)) if a < b)
+	};
+	assert_eq!(minimize(vec![5, 2, 9, 5, 1, 2, 5, 2], keep), [2, 5]);
+}
+
+#[test]
+fn counts_are_met_exactly() {
+	let keep = |v: &[u32]| v.iter().filter(|&&x| x % 2 == 0).count() >= 3;
+	let min = minimize((1..=50).collect(), keep);
+	assert_eq!(min.len(), 3);
+	assert!(min.iter().all(|x| x % 2 == 0));
+
+	let keep = |v: &[u32]| v.iter().sum::<u32>() >= 10;
+	let min = minimize(vec![1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1], keep);
+	assert_eq!(min, [1; 10]);
+}
+
+#[test]
+fn an_item_is_always_left() {
+	assert_eq!(minimize((0..10).collect(), |_| true).len(), 1);
+	assert_eq!(minimize(vec![7], |_| true), [7]);
+	assert_eq!(minimize(vec![], |_| true), [0; 0]);
+}
+
+#[test]
+fn nothing_goes_if_every_item_is_needed() {
+	assert_eq!(minimize((0..9).collect(), |v| v.len() == 9), (0..9).collect::<Vec<_>>());
+}
+
+#[test]
+fn errors_stop_the_search() {
+	let mut calls = 0;
+	let res = ddmin((0..16).collect::<Vec<u32>>(), |v| {
+		calls += 1;
+		if v.len() < 8 { Err(Error::from("too short")) } else { Ok(true) }
+	});
+	assert_eq!(res.unwrap_err().to_string(), "too short");
+	assert!(calls <= 3, "{}", calls);
+}
@@ -0,0 +1,38 @@
+use std::env;
+use std::fs;
+use std::path::Path;
+use std::process::Command;
+
+fn detect(path: &Path) -> (bool, String) {
+	let out = Command::new(env!("CARGO_BIN_EXE_aoc"))
+		.arg("detect")
+		.arg(path)
+		.output()
+		.unwrap();
+	(out.status.success(), String::from_utf8_lossy(&out.stdout).into_owned())
+}
+
+#[test]
+fn examples_are_their_own_day() {
+	let root = Path::new(env!("CARGO_MANIFEST_DIR")).join("..");
+	for day in 1..=15 {
+		let path = root.join(format!("day{}/example.txt", day));
+		let (ok, stdout) = detect(&path);
+		assert!(ok, "{}", stdout);
+		let best = stdout.split_once(": day ").map(|(_, rest)| rest);
+		let expected = format!("{} (", day);
+		assert!(best.is_some_and(|b| b.starts_with(&expected)), "day {}: {}", day, stdout);
+		assert!(!stdout.contains("doesn't parse"), "day {}: {}", day, stdout);
+	}
+}
+
+#[test]
+fn other_text_is_no_day() {
+	let path = env::temp_dir().join(format!("aoc-detect-{}.txt", std::process::id()));
+	// Day 6's parser takes any line, but that alone is not enough
+	fs::write(&path, "Dear diary,\nnothing happened today.\n").unwrap();
+	let (ok, stdout) = detect(&path);
+	fs::remove_file(&path).unwrap();
+	assert!(!ok);
+	assert!(stdout.ends_with(": unknown\n"), "{}", stdout);
+}
@@ -0,0 +1,41 @@
+use common::{Generate, Rng, Solution};
+
+const SEEDS: [u64; 4] = [0, 1, 42, 2022];
+const SCALES: [usize; 2] = [1, 3];
+
+fn parses<S: Generate + Solution>(day: u8) {
+	for seed in SEEDS {
+		for scale in SCALES {
+			let inp = S::generate(&mut Rng::new(seed), scale);
+			if let Err(e) = S::parse(&inp) {
+				panic!("day {} seed {} scale {}: {}", day, seed, scale, e.snippet(&inp));
+			}
+		}
+	}
+}
+
+#[test]
+fn generated_inputs_parse() {
+	parses::<day1::Day1>(1);
+	parses::<day2::Day2>(2);
+	parses::<day3::Day3>(3);
+	parses::<day4::Day4>(4);
+	parses::<day5::Day5>(5);
+	parses::<day6::Day6>(6);
+	parses::<day7::Day7>(7);
+	parses::<day8::Day8>(8);
+	parses::<day9::Day9>(9);
+	parses::<day10::Day10>(10);
+	parses::<day11::Day11>(11);
+	parses::<day12::Day12>(12);
+	parses::<day13::Day13>(13);
+	parses::<day14::Day14>(14);
+	parses::<day15::Day15>(15);
+}
+
+#[test]
+fn day11_scales_monkeys() {
+	let count = |scale| day11::Day11::generate(&mut Rng::new(7), scale).matches("Monkey").count();
+	assert_eq!(count(1), 8);
+	assert!(count(10) > 8);
+}
@@ -0,0 +1,86 @@
+#[path = "../src/glob.rs"]
+mod glob;
+
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use glob::{expand, matches};
+
+fn glob(pattern: &str, name: &str) -> bool {
+	let chars = |s: &str| s.chars().collect::<Vec<_>>();
+	matches(&chars(pattern), &chars(name))
+}
+
+#[test]
+fn star_matches_any_run() {
+	assert!(glob("*", "input.txt"));
+	assert!(glob("*", ""));
+	assert!(glob("*.txt", "input.txt"));
+	assert!(glob("*.txt", ".txt"));
+	assert!(glob("in*t.txt", "input.txt"));
+	assert!(glob("*put*", "input.txt"));
+	assert!(glob("**.txt", "a.txt"));
+	assert!(!glob("*.txt", "input.toml"));
+	assert!(!glob("in*x", "input.txt"));
+}
+
+#[test]
+fn question_mark_matches_one_character() {
+	assert!(glob("day?.txt", "day1.txt"));
+	assert!(glob("day??.txt", "day12.txt"));
+	assert!(glob("?*", "a"));
+	assert!(!glob("day?.txt", "day12.txt"));
+	assert!(!glob("day?.txt", "day.txt"));
+	assert!(!glob("?", ""));
+}
+
+#[test]
+fn other_characters_match_themselves() {
+	assert!(glob("input.txt", "input.txt"));
+	assert!(glob("", ""));
+	assert!(!glob("input.txt", "input.txt2"));
+	assert!(!glob("Input.txt", "input.txt"));
+	assert!(!glob("input", "input.txt"));
+}
+
+// A directory of inputs with an answer file, next to another one
+fn tree() -> PathBuf {
+	let root = env::temp_dir().join(format!("aoc-glob-{}", std::process::id()));
+	for file in ["inputs/a1.txt", "inputs/a2.txt", "inputs/b10.txt", "inputs/a1.toml", "other/a3.txt"] {
+		let path = root.join(file);
+		fs::create_dir_all(path.parent().unwrap()).unwrap();
+		fs::write(path, "").unwrap();
+	}
+	root
+}
+
+fn names(root: &Path, pattern: &str) -> Vec<String> {
+	expand(&format!("{}/{}", root.display(), pattern))
+		.unwrap()
+		.iter()
+		.map(|p| p.strip_prefix(root).unwrap().display().to_string())
+		.collect()
+}
+
+#[test]
+fn patterns_expand_to_input_files() {
+	let root = tree();
+	assert_eq!(names(&root, "inputs"), ["inputs/a1.txt", "inputs/a2.txt", "inputs/b10.txt"]);
+	assert_eq!(names(&root, "inputs/a1.txt"), ["inputs/a1.txt"]);
+	assert_eq!(names(&root, "inputs/a*"), ["inputs/a1.txt", "inputs/a2.txt"]);
+	assert_eq!(names(&root, "inputs/?1.*"), ["inputs/a1.txt"]);
+	assert_eq!(names(&root, "inputs/b??.txt"), ["inputs/b10.txt"]);
+	// Only the last component is a pattern
+	assert_eq!(names(&root, "other/*.txt"), ["other/a3.txt"]);
+
+	for pattern in ["inputs/c*", "inputs/*.toml", "inputs/a?.txt2", "missing"] {
+		let pattern = format!("{}/{}", root.display(), pattern);
+		let err = expand(&pattern).unwrap_err();
+		assert_eq!(err.to_string(), format!("no input files match {}", pattern));
+	}
+	// Wildcards in directories aren't expanded
+	let err = expand(&format!("{}/*/a1.txt", root.display())).unwrap_err();
+	assert!(err.to_string().starts_with("cannot list "), "{}", err);
+	fs::remove_dir_all(root).unwrap();
+}
@@ -0,0 +1,25 @@
+use std::env;
+use std::fs;
+use std::process::Command;
+
+// A sensor covering the whole search area, which part 2 then scans row by
+// row without end in sight
+const INPUT: &str = "Sensor at x=0, y=0: closest beacon is at x=1000000000, y=0\n";
+
+#[test]
+fn timed_out_part_is_reported() {
+	let path = env::temp_dir().join(format!("aoc-timeout-{}.txt", std::process::id()));
+	fs::write(&path, INPUT).unwrap();
+	let out = Command::new(env!("CARGO_BIN_EXE_aoc"))
+		.args(["run", "15", "--set", "limit=100000000", "--timeout", "0.2", "--input"])
+		.arg(&path)
+		.output()
+		.unwrap();
+	fs::remove_file(&path).unwrap();
+
+	let stdout = String::from_utf8_lossy(&out.stdout);
+	let stderr = String::from_utf8_lossy(&out.stderr);
+	assert_eq!(out.status.code(), Some(1), "{}", stderr);
+	assert!(stdout.contains("Part 1: "), "{}", stdout);
+	assert!(stderr.contains("part 2 timed out after 0.2s"), "{}", stderr);
+}
@@ -0,0 +1,188 @@
+use std::borrow::Cow;
+use std::env;
+use std::fmt;
+use std::fs;
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+use std::process;
+
+use crate::Error;
+
+/// The environment variable naming the input file when none is given.
+pub const INPUT_VAR: &str = "AOC_INPUT";
+
+/// Where puzzle input is read from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Source {
+	Stdin,
+	File(PathBuf),
+}
+
+impl Source {
+	/// A path argument, with `-` standing for stdin.
+	pub fn from_arg(arg: impl Into<PathBuf>) -> Self {
+		let path = arg.into();
+		match path.as_os_str() == "-" {
+			true => Self::Stdin,
+			false => Self::File(path),
+		}
+	}
+
+	/// The source named by `AOC_INPUT`, if set.
+	pub fn from_env() -> Option<Self> {
+		env::var_os(INPUT_VAR)
+			.filter(|val| !val.is_empty())
+			.map(Self::from_arg)
+	}
+
+	pub fn path(&self) -> Option<&Path> {
+		match self {
+			Self::Stdin => None,
+			Self::File(path) => Some(path),
+		}
+	}
+
+	/// Reads the input and normalizes it, warning on stderr about anything
+	/// that had to be fixed.
+	pub fn read(&self) -> Result<String, Error> {
+		let (inp, fixes) = normalize(&self.read_raw()?);
+		for fix in fixes {
+			eprintln!("warning: {}: {}", self, fix);
+		}
+		Ok(inp)
+	}
+
+	/// Reads the input exactly as it is.
+	pub fn read_raw(&self) -> Result<String, Error> {
+		let res = match self {
+			Self::Stdin => {
+				let mut inp = String::new();
+				io::stdin().read_to_string(&mut inp).map(|_| inp)
+			}
+			Self::File(path) => fs::read_to_string(path),
+		};
+		res.map_err(|e| match e.kind() {
+			io::ErrorKind::NotFound => format!("input file {} not found", self),
+			_ => format!("cannot read input from {}: {}", self, e),
+		}.into())
+	}
+}
+
+impl fmt::Display for Source {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self {
+			Self::Stdin => write!(f, "stdin"),
+			Self::File(path) => write!(f, "{}", path.display()),
+		}
+	}
+}
+
+// Describes how many lines a fix applied to, and the first of them
+fn lines_fixed(what: &str, lines: &[usize]) -> Option<String> {
+	match lines {
+		[] => None,
+		[line] => Some(format!("{} on line {}", what, line + 1)),
+		[line, ..] => Some(format!("{} on {} lines, starting at line {}",
+			what, lines.len(), line + 1)),
+	}
+}
+
+// Turns each `\r` not followed by `\n` into a line break, returning the
+// lines it ended
+fn convert_lone_cr(inp: &str) -> (Cow<'_, str>, Vec<usize>) {
+	if !inp.contains('\r') {
+		return (Cow::Borrowed(inp), Vec::new());
+	}
+	let mut out = String::with_capacity(inp.len());
+	let mut lone = Vec::new();
+	let mut line = 0;
+	let mut chars = inp.chars().peekable();
+	while let Some(c) = chars.next() {
+		match c {
+			'\r' if chars.peek() != Some(&'\n') => {
+				lone.push(line);
+				out.push('\n');
+				line += 1;
+			}
+			'\n' => {
+				out.push(c);
+				line += 1;
+			}
+			c => out.push(c),
+		}
+	}
+	(Cow::Owned(out), lone)
+}
+
+/// Brings puzzle input to the exact format the parsers expect: no byte
+/// order mark, `\n` line endings rather than `\r\n` or a lone `\r`, no
+/// trailing whitespace on any line, and a single final newline. Returns the
+/// fixed input along with a description of each kind of fix made.
+pub fn normalize(raw: &str) -> (String, Vec<String>) {
+	let mut fixes = Vec::new();
+	let inp = match raw.strip_prefix('\u{feff}') {
+		Some(rest) => {
+			fixes.push("removed a UTF-8 byte order mark".to_string());
+			rest
+		}
+		None => raw,
+	};
+
+	let (inp, lone) = convert_lone_cr(inp);
+	let mut crlf = Vec::new();
+	let mut trailing = Vec::new();
+	let mut lines = Vec::new();
+	for (i, line) in inp.split('\n').enumerate() {
+		let line = match line.strip_suffix('\r') {
+			Some(line) => {
+				crlf.push(i);
+				line
+			}
+			None => line,
+		};
+		let trimmed = line.trim_end();
+		if trimmed.len() != line.len() {
+			trailing.push(i);
+		}
+		lines.push(trimmed);
+	}
+	fixes.extend(lines_fixed("converted CR line endings", &lone));
+	fixes.extend(lines_fixed("converted CRLF line endings", &crlf));
+	fixes.extend(lines_fixed("removed trailing whitespace", &trailing));
+
+	// The split leaves an empty last line when the input ends in a newline
+	let blank_end = lines.iter().rev().take_while(|l| l.is_empty()).count();
+	match blank_end {
+		0 if !lines.is_empty() && !inp.is_empty() =>
+			fixes.push("added a missing final newline".to_string()),
+		0 | 1 => (),
+		n => fixes.push(format!("removed {} blank lines at the end", n - 1)),
+	}
+	lines.truncate(lines.len() - blank_end);
+
+	let mut out = lines.join("\n");
+	if !out.is_empty() {
+		out.push('\n');
+	}
+	(out, fixes)
+}
+
+/// The input of a standalone binary: the first argument that isn't a flag,
+/// then `AOC_INPUT`, then `./input.txt`.
+pub fn source_from_args() -> Source {
+	crate::positional_args()
+		.into_iter()
+		.next()
+		.map(Source::from_arg)
+		.or_else(Source::from_env)
+		.unwrap_or_else(|| Source::File(PathBuf::from("./input.txt")))
+}
+
+/// Reads the input of a standalone binary, printing the error and exiting
+/// on failure.
+pub fn read_or_exit() -> String {
+	source_from_args().read().unwrap_or_else(|e| {
+		eprintln!("error: {}", e);
+		process::exit(1);
+	})
+}
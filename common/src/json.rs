@@ -0,0 +1,140 @@
+use std::fmt::{self, Write};
+
+/// A JSON value, written out compactly by its `Display` implementation.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Json {
+	Null,
+	Bool(bool),
+	Int(i64),
+	UInt(u64),
+	Float(f64),
+	String(String),
+	Array(Vec<Json>),
+	Object(Vec<(String, Json)>),
+}
+
+impl Json {
+	pub fn object<K: Into<String>>(fields: impl IntoIterator<Item=(K, Json)>)
+		-> Self
+	{
+		Self::Object(fields.into_iter().map(|(k, v)| (k.into(), v)).collect())
+	}
+}
+
+fn write_str(f: &mut fmt::Formatter<'_>, s: &str) -> fmt::Result {
+	f.write_char('"')?;
+	for c in s.chars() {
+		match c {
+			'"' => f.write_str("\\\"")?,
+			'\\' => f.write_str("\\\\")?,
+			'\n' => f.write_str("\\n")?,
+			'\r' => f.write_str("\\r")?,
+			'\t' => f.write_str("\\t")?,
+			c if c.is_control() => write!(f, "\\u{:04x}", c as u32)?,
+			c => f.write_char(c)?,
+		}
+	}
+	f.write_char('"')
+}
+
+impl fmt::Display for Json {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self {
+			Self::Null => f.write_str("null"),
+			Self::Bool(b) => write!(f, "{}", b),
+			Self::Int(n) => write!(f, "{}", n),
+			Self::UInt(n) => write!(f, "{}", n),
+			Self::Float(n) if n.is_finite() => write!(f, "{}", n),
+			Self::Float(_) => f.write_str("null"),
+			Self::String(s) => write_str(f, s),
+			Self::Array(items) => {
+				f.write_char('[')?;
+				for (i, item) in items.iter().enumerate() {
+					if i > 0 {
+						f.write_char(',')?;
+					}
+					write!(f, "{}", item)?;
+				}
+				f.write_char(']')
+			}
+			Self::Object(fields) => {
+				f.write_char('{')?;
+				for (i, (key, value)) in fields.iter().enumerate() {
+					if i > 0 {
+						f.write_char(',')?;
+					}
+					write_str(f, key)?;
+					write!(f, ":{}", value)?;
+				}
+				f.write_char('}')
+			}
+		}
+	}
+}
+
+/// Conversion of answers and parsed inputs into JSON.
+pub trait ToJson {
+	fn to_json(&self) -> Json;
+}
+
+macro_rules! impl_to_json {
+	($variant:ident as $conv:ty: $($t:ty),*) => {
+		$(impl ToJson for $t {
+			fn to_json(&self) -> Json {
+				Json::$variant(*self as $conv)
+			}
+		})*
+	};
+}
+
+impl_to_json!(Int as i64: i32, i64, isize);
+impl_to_json!(UInt as u64: u8, u32, u64, usize);
+
+impl ToJson for bool {
+	fn to_json(&self) -> Json {
+		Json::Bool(*self)
+	}
+}
+
+impl ToJson for char {
+	fn to_json(&self) -> Json {
+		Json::String(self.to_string())
+	}
+}
+
+impl ToJson for str {
+	fn to_json(&self) -> Json {
+		Json::String(self.to_string())
+	}
+}
+
+impl ToJson for String {
+	fn to_json(&self) -> Json {
+		Json::String(self.clone())
+	}
+}
+
+impl<T: ToJson> ToJson for [T] {
+	fn to_json(&self) -> Json {
+		Json::Array(self.iter().map(T::to_json).collect())
+	}
+}
+
+impl<T: ToJson> ToJson for Vec<T> {
+	fn to_json(&self) -> Json {
+		self.as_slice().to_json()
+	}
+}
+
+impl<T: ToJson> ToJson for Option<T> {
+	fn to_json(&self) -> Json {
+		self.as_ref().map_or(Json::Null, T::to_json)
+	}
+}
+
+// Pairs become arrays of two
+impl<A: ToJson, B: ToJson> ToJson for (A, B) {
+	fn to_json(&self) -> Json {
+		Json::Array(vec![self.0.to_json(), self.1.to_json()])
+	}
+}
@@ -0,0 +1,165 @@
+use std::any::Any;
+use std::env;
+use std::fmt::Display;
+use std::path::PathBuf;
+use std::process;
+use std::time::{Duration, Instant};
+
+pub mod input;
+pub mod json;
+pub mod params;
+mod parse;
+pub mod progress;
+pub mod rng;
+pub mod toml;
+
+pub use parse::{ParseError, Scanner, blocks, lines, parse_lines};
+pub use input::Source;
+pub use json::{Json, ToJson};
+pub use params::Params;
+pub use rng::Rng;
+
+pub type Error = Box<dyn std::error::Error + Send + Sync>;
+
+/// A puzzle solution for a single day.
+///
+/// The raw puzzle text is parsed once into `Input`, which is then shared
+/// by both parts. Puzzles with parameters outside of the text, like sizes
+/// or round counts, keep them in `Input` with the real puzzle's values.
+/// `Input` converts to JSON so that the parsed structure can be inspected.
+pub trait Solution {
+	type Input: ToJson;
+	type Answer1: Display + ToJson;
+	type Answer2: Display + ToJson;
+
+	fn parse(inp: &str) -> Result<Self::Input, ParseError>;
+	fn part1(input: &Self::Input) -> Self::Answer1;
+	fn part2(input: &Self::Input) -> Self::Answer2;
+
+	/// Overrides the parameters kept in a parsed input. Days without any
+	/// reject every override.
+	fn configure(_input: &mut Self::Input, params: &Params) -> Result<(), Error> {
+		params.expect(&[])
+	}
+}
+
+/// An answer that some inputs don't have, such as a search coming up empty
+/// once parameters shrink the area searched.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Found<T>(pub Option<T>);
+
+impl<T: Display> Display for Found<T> {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		match &self.0 {
+			Some(ans) => ans.fmt(f),
+			None => f.write_str("no answer"),
+		}
+	}
+}
+
+impl<T: ToJson> ToJson for Found<T> {
+	fn to_json(&self) -> Json {
+		self.0.to_json()
+	}
+}
+
+/// A generator of random puzzle inputs in a day's exact format.
+pub trait Generate {
+	/// Generates a valid input about `scale` times the size of a real one.
+	fn generate(rng: &mut Rng, scale: usize) -> String;
+}
+
+/// The message a panic was raised with, from the payload `catch_unwind`
+/// returns.
+pub fn panic_message(payload: &(dyn Any + Send)) -> String {
+	match payload.downcast_ref::<&str>() {
+		Some(msg) => msg.to_string(),
+		None => payload.downcast_ref::<String>()
+			.cloned()
+			.unwrap_or_else(|| "unknown panic".to_string()),
+	}
+}
+
+/// Parses the input of a standalone binary, printing the error with the
+/// offending line and exiting on failure.
+pub fn parse_or_exit<S: Solution>(inp: &str) -> S::Input {
+	S::parse(inp).unwrap_or_else(|e| {
+		eprintln!("error: {}", e.snippet(inp));
+		process::exit(1);
+	})
+}
+
+// Flags of standalone binaries that are followed by a value
+const VALUE_FLAGS: &[&str] = &["--config", "--set"];
+
+/// The arguments of a standalone binary that are neither flags nor their
+/// values.
+pub fn positional_args() -> Vec<String> {
+	let mut args = env::args().skip(1);
+	let mut out = Vec::new();
+	while let Some(arg) = args.next() {
+		match arg.as_str() {
+			flag if VALUE_FLAGS.contains(&flag) => { args.next(); }
+			flag if flag.starts_with("--") => (),
+			_ => out.push(arg),
+		}
+	}
+	out
+}
+
+// Every value given to a flag of a standalone binary
+fn flag_values(flag: &str) -> Vec<String> {
+	env::args()
+		.skip(1)
+		.collect::<Vec<_>>()
+		.windows(2)
+		.filter(|w| w[0] == flag)
+		.map(|w| w[1].clone())
+		.collect()
+}
+
+/// Applies the parameters of a standalone binary, from the config file
+/// given with `--config` and from `--set name=value` arguments, exiting on
+/// failure.
+pub fn configure_or_exit<S: Solution>(day: u8, input: &mut S::Input) {
+	let res = (|| {
+		let config = flag_values("--config").pop().map(PathBuf::from);
+		let doc = params::load_config(config.as_deref())?;
+		let mut params = Params::from_config(&doc, day)?;
+		for arg in flag_values("--set") {
+			params.set_arg(&arg)?;
+		}
+		S::configure(input, &params)
+	})();
+	if let Err(e) = res {
+		eprintln!("error: {}", e);
+		process::exit(1);
+	}
+}
+
+/// A single answer as emitted in JSON output mode, with how long solving
+/// its part took.
+pub fn json_record(day: u8, part: u8, answer: Json, duration: Duration) -> Json {
+	Json::object([
+		("day", Json::UInt(day as u64)),
+		("part", Json::UInt(part as u64)),
+		("answer", answer),
+		("duration", Json::Float(duration.as_secs_f64())),
+	])
+}
+
+/// Whether a standalone binary was asked for JSON output with `--json`.
+pub fn json_requested() -> bool {
+	env::args().skip(1).any(|arg| arg == "--json")
+}
+
+/// Solves both parts, printing one JSON record per line.
+pub fn print_json<S: Solution>(day: u8, input: &S::Input) {
+	let start = Instant::now();
+	let answer = S::part1(input).to_json();
+	println!("{}", json_record(day, 1, answer, start.elapsed()));
+
+	let start = Instant::now();
+	let answer = S::part2(input).to_json();
+	println!("{}", json_record(day, 2, answer, start.elapsed()));
+}
@@ -0,0 +1,120 @@
+use std::collections::BTreeMap;
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::Error;
+use crate::toml::{Document, Value};
+
+/// The environment variable naming the config file when none is given.
+pub const CONFIG_VAR: &str = "AOC_CONFIG";
+
+/// The config file looked for in the current directory otherwise.
+pub const CONFIG_FILE: &str = "aoc.toml";
+
+/// Overrides for puzzle parameters that are not part of the puzzle text,
+/// such as sizes and round counts, as `name = integer` pairs.
+///
+/// They come from the `[dayN]` table of a config file, then from
+/// `--set name=value` arguments.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Params {
+	values: BTreeMap<String, i64>,
+}
+
+impl Params {
+	pub fn new() -> Self {
+		Self::default()
+	}
+
+	/// Reads the parameters of a day from the `[dayN]` table of a config.
+	pub fn from_config(doc: &Document, day: u8) -> Result<Self, Error> {
+		let table = format!("day{}.", day);
+		let mut params = Self::new();
+		for (key, value) in doc.iter() {
+			let Some(name) = key.strip_prefix(&table) else {
+				continue;
+			};
+			match value {
+				Value::Integer(n) => params.set(name, *n),
+				_ => return Err(format!("{}: expected an integer", key).into()),
+			}
+		}
+		Ok(params)
+	}
+
+	/// Parses a `name=value` override.
+	pub fn set_arg(&mut self, arg: &str) -> Result<(), Error> {
+		let (name, value) = arg.split_once('=')
+			.ok_or_else(|| format!("expected `name=value`, found `{}`", arg))?;
+		let value = value.trim().replace('_', "").parse::<i64>()
+			.map_err(|_| format!("invalid value for {}: {}", name, value))?;
+		self.set(name.trim(), value);
+		Ok(())
+	}
+
+	pub fn set(&mut self, name: &str, value: i64) {
+		self.values.insert(name.to_string(), value);
+	}
+
+	pub fn get(&self, name: &str) -> Option<i64> {
+		self.values.get(name).copied()
+	}
+
+	/// Adds `other` on top, replacing values set in both.
+	pub fn extend(&mut self, other: Params) {
+		self.values.extend(other.values);
+	}
+
+	pub fn is_empty(&self) -> bool {
+		self.values.is_empty()
+	}
+
+	/// Checks that every parameter is one of `known`.
+	pub fn expect(&self, known: &[&str]) -> Result<(), Error> {
+		let Some(name) = self.values.keys().find(|k| !known.contains(&k.as_str())) else {
+			return Ok(());
+		};
+		match known {
+			[] => Err(format!("unknown parameter {}: this day takes none", name).into()),
+			_ => Err(format!("unknown parameter {}, expected one of: {}",
+				name, known.join(", ")).into()),
+		}
+	}
+
+	/// Sets `target` to the named parameter if it was given and is at least
+	/// `min`.
+	pub fn apply<T>(&self, name: &str, min: T, target: &mut T) -> Result<(), Error>
+	where
+		T: TryFrom<i64> + PartialOrd + std::fmt::Display,
+	{
+		let Some(value) = self.get(name) else {
+			return Ok(());
+		};
+		match T::try_from(value) {
+			Ok(v) if v >= min => {
+				*target = v;
+				Ok(())
+			}
+			_ => Err(format!("{} must be at least {}, got {}", name, min, value).into()),
+		}
+	}
+}
+
+/// Loads the config file at `path`, or else the one named by `AOC_CONFIG`,
+/// or else `aoc.toml` if there is one. With none of them, the config is
+/// empty.
+pub fn load_config(path: Option<&Path>) -> Result<Document, Error> {
+	let path = match path {
+		Some(path) => path.to_path_buf(),
+		None => match env::var_os(CONFIG_VAR).filter(|v| !v.is_empty()) {
+			Some(path) => PathBuf::from(path),
+			None if Path::new(CONFIG_FILE).exists() => PathBuf::from(CONFIG_FILE),
+			None => return Ok(Document::new()),
+		},
+	};
+	let src = fs::read_to_string(&path)
+		.map_err(|e| format!("cannot read config {}: {}", path.display(), e))?;
+	Document::parse(&src)
+		.map_err(|e| format!("{}: {}", path.display(), e.snippet(&src)).into())
+}
@@ -0,0 +1,221 @@
+use std::error::Error;
+use std::fmt;
+use std::str::FromStr;
+
+/// An error found while parsing puzzle input.
+///
+/// `line` and `col` are 0-based and relative to whatever text was being
+/// parsed; callers parsing a sub-slice shift them with `at_line()` and
+/// `at_col()` so that they end up relative to the whole input.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+	pub line: usize,
+	pub col: usize,
+	pub found: String,
+	pub expected: String,
+}
+
+impl ParseError {
+	pub fn new(col: usize, found: &str, expected: impl Into<String>) -> Self {
+		Self {
+			line: 0,
+			col,
+			found: found.to_string(),
+			expected: expected.into(),
+		}
+	}
+
+	pub fn at_line(mut self, line: usize) -> Self {
+		self.line += line;
+		self
+	}
+
+	pub fn at_col(mut self, col: usize) -> Self {
+		self.col += col;
+		self
+	}
+
+	/// Renders the error along with the offending line of `src` and a
+	/// caret under the offending text.
+	pub fn snippet(&self, src: &str) -> String {
+		let line = src.split('\n').nth(self.line).unwrap_or("");
+		let lineno = (self.line + 1).to_string();
+		let pad = " ".repeat(lineno.len());
+		let width = self.found.chars().count().max(1);
+		format!("{}\n{} |\n{} | {}\n{} | {}{}",
+			self, pad, lineno, line, pad,
+			" ".repeat(self.col), "^".repeat(width))
+	}
+}
+
+impl fmt::Display for ParseError {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		write!(f, "line {}, column {}: expected {}, found ",
+			self.line + 1, self.col + 1, self.expected)?;
+		if self.found.is_empty() {
+			return write!(f, "end of line");
+		}
+		// Control characters such as a stray `\r` would garble the message
+		let found: String = self.found.chars()
+			.map(|c| match c.is_control() {
+				true => c.escape_default().to_string(),
+				false => c.to_string(),
+			})
+			.collect();
+		write!(f, "`{}`", found)
+	}
+}
+
+impl Error for ParseError {}
+
+// The offending text at the start of `rest`: the next word, or else the
+// whitespace character in the way, so that only the actual end of the line
+// reads as such
+pub(crate) fn found_at(rest: &str) -> &str {
+	match rest.split(|c: char| c.is_whitespace()).next() {
+		Some("") | None => match rest.chars().next() {
+			Some('\n') | None => "",
+			Some(c) => &rest[..c.len_utf8()],
+		},
+		Some(word) => word,
+	}
+}
+
+/// Non-empty lines along with their 0-based line number.
+pub fn lines(inp: &str) -> impl Iterator<Item=(usize, &str)> {
+	inp.split('\n')
+		.enumerate()
+		.filter(|(_, ln)| !ln.trim().is_empty())
+}
+
+/// Non-empty blocks separated by a blank line, along with the 0-based
+/// line number where each of them starts.
+pub fn blocks(inp: &str) -> impl Iterator<Item=(usize, &str)> {
+	let mut line = 0;
+	inp.split("\n\n")
+		.map(move |block| {
+			let start = line;
+			line += block.matches('\n').count() + 2;
+			(start, block)
+		})
+		.filter(|(_, block)| !block.trim().is_empty())
+}
+
+/// Parses every non-empty line with `f`, placing errors at the right line.
+pub fn parse_lines<T, F>(inp: &str, mut f: F) -> Result<Vec<T>, ParseError>
+where
+	F: FnMut(&str) -> Result<T, ParseError>
+{
+	lines(inp)
+		.map(|(i, ln)| f(ln).map_err(|e| e.at_line(i)))
+		.collect()
+}
+
+/// A cursor over a single line that produces positioned errors.
+#[derive(Debug, Clone)]
+pub struct Scanner<'a> {
+	line: &'a str,
+	pos: usize,
+}
+
+impl<'a> Scanner<'a> {
+	pub fn new(line: &'a str) -> Self {
+		Self { line, pos: 0 }
+	}
+
+	/// Current character column
+	pub fn col(&self) -> usize {
+		self.line[..self.pos].chars().count()
+	}
+
+	pub fn rest(&self) -> &'a str {
+		&self.line[self.pos..]
+	}
+
+	pub fn is_empty(&self) -> bool {
+		self.rest().is_empty()
+	}
+
+	pub fn peek(&self) -> Option<char> {
+		self.rest().chars().next()
+	}
+
+	/// Builds an error at the current position, reporting the next word,
+	/// or the whitespace character in the way, as the offending text.
+	pub fn error(&self, expected: impl Into<String>) -> ParseError {
+		ParseError::new(self.col(), found_at(self.rest()), expected)
+	}
+
+	pub fn skip_whitespace(&mut self) {
+		let rest = self.rest();
+		self.pos += rest.len() - rest.trim_start().len();
+	}
+
+	pub fn eat(&mut self, c: char) -> bool {
+		let found = self.peek() == Some(c);
+		if found {
+			self.pos += c.len_utf8();
+		}
+		found
+	}
+
+	pub fn tag(&mut self, tag: &str) -> Result<(), ParseError> {
+		match self.rest().starts_with(tag) {
+			true => {
+				self.pos += tag.len();
+				Ok(())
+			}
+			false if tag.trim().is_empty() => Err(self.error("a space")),
+			false => Err(self.error(format!("`{}`", tag.trim()))),
+		}
+	}
+
+	/// Consumes characters while `pred` holds and returns them.
+	pub fn take_while<P>(&mut self, pred: P) -> &'a str
+	where
+		P: Fn(char) -> bool
+	{
+		let rest = self.rest();
+		let len = rest.find(|c| !pred(c)).unwrap_or(rest.len());
+		self.pos += len;
+		&rest[..len]
+	}
+
+	pub fn word(&mut self) -> Result<&'a str, ParseError> {
+		match self.take_while(|c| !c.is_whitespace()) {
+			"" => Err(self.error("a word")),
+			w => Ok(w),
+		}
+	}
+
+	/// Parses an optionally signed integer.
+	pub fn number<T: FromStr>(&mut self) -> Result<T, ParseError> {
+		let start = self.pos;
+		self.eat('-');
+		self.take_while(|c| c.is_ascii_digit());
+		match self.line[start..self.pos].parse::<T>() {
+			Ok(v) => Ok(v),
+			Err(_) => {
+				self.pos = start;
+				Err(self.error("a number"))
+			}
+		}
+	}
+
+	/// Parses the next word with the given type's `FromStr`, which is
+	/// expected to report errors relative to the word.
+	pub fn parse<T>(&mut self) -> Result<T, ParseError>
+	where
+		T: FromStr<Err = ParseError>
+	{
+		let col = self.col();
+		self.word()?.parse::<T>().map_err(|e| e.at_col(col))
+	}
+
+	pub fn end(&self) -> Result<(), ParseError> {
+		match self.is_empty() {
+			true => Ok(()),
+			false => Err(self.error("end of line")),
+		}
+	}
+}
@@ -0,0 +1,149 @@
+//! Progress reporting and cancellation for long-running loops.
+//!
+//! Solvers call [`report`] from their main loop. Outside of [`run`] that
+//! does nothing, so solvers keep their plain signatures. Inside it, progress
+//! is drawn on stderr and, once the time budget is spent, the computation
+//! unwinds back to [`run`], which returns [`TimedOut`].
+
+use std::cell::{Cell, RefCell};
+use std::fmt;
+use std::io::{self, IsTerminal, Write};
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::Mutex;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::{Duration, Instant};
+
+// How often the progress line is redrawn
+const REDRAW: Duration = Duration::from_millis(100);
+// Reports come from loops of any grain, from a round of day 11 to a cell of
+// day 12, so the clock is read on one report in a stride that adapts to
+// keep readings about this far apart
+const CHECK_INTERVAL: Duration = Duration::from_millis(1);
+const MAX_STRIDE: u32 = 1 << 16;
+
+static BUDGET: Mutex<Option<Duration>> = Mutex::new(None);
+static VISIBLE: AtomicBool = AtomicBool::new(false);
+
+/// Limits every later [`run`] to `budget`, or lifts the limit.
+pub fn set_budget(budget: Option<Duration>) {
+	*BUDGET.lock().unwrap() = budget;
+}
+
+pub fn budget() -> Option<Duration> {
+	*BUDGET.lock().unwrap()
+}
+
+/// Shows progress on stderr during every later [`run`], if it's a terminal.
+pub fn set_visible(visible: bool) {
+	VISIBLE.store(visible && io::stderr().is_terminal(), Ordering::Relaxed);
+}
+
+/// A computation that ran out of its time budget, with how long it ran.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TimedOut(pub Duration);
+
+impl fmt::Display for TimedOut {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		write!(f, "timed out after {:.1}s", self.0.as_secs_f64())
+	}
+}
+
+impl std::error::Error for TimedOut {}
+
+struct Context {
+	label: String,
+	deadline: Option<Instant>,
+	visible: bool,
+	drawn: Option<Instant>,
+	start: Instant,
+	// Reports between clock readings, and when it was last read
+	stride: u32,
+	checked: Instant,
+}
+
+thread_local! {
+	static CONTEXT: RefCell<Option<Context>> = const { RefCell::new(None) };
+	// Reports left until the clock is read again
+	static COUNTDOWN: Cell<u32> = const { Cell::new(0) };
+}
+
+// The payload unwound with once the budget is spent, which `run` tells
+// apart from real panics
+struct Cancelled;
+
+/// Runs `f` with progress shown under `label` and the current budget, if
+/// any. Panics in `f` are passed on.
+pub fn run<T>(label: &str, f: impl FnOnce() -> T) -> Result<T, TimedOut> {
+	let start = Instant::now();
+	let ctx = Context {
+		label: label.to_string(),
+		deadline: budget().map(|b| start + b),
+		visible: VISIBLE.load(Ordering::Relaxed),
+		drawn: None,
+		start,
+		stride: 1,
+		checked: start,
+	};
+	let outer = CONTEXT.with(|c| c.replace(Some(ctx)));
+	COUNTDOWN.with(|c| c.set(1));
+	let res = panic::catch_unwind(AssertUnwindSafe(f));
+	let ctx = CONTEXT.with(|c| c.replace(outer)).unwrap();
+	COUNTDOWN.with(|c| c.set(1));
+
+	if ctx.drawn.is_some() {
+		eprint!("\r\x1b[K");
+	}
+	match res {
+		Ok(val) => Ok(val),
+		Err(payload) if payload.is::<Cancelled>() => Err(TimedOut(ctx.start.elapsed())),
+		Err(payload) => panic::resume_unwind(payload),
+	}
+}
+
+/// Reports that `done` out of `total`, if known, units of work are
+/// complete. Unwinds to the enclosing [`run`] once its budget is spent.
+pub fn report(done: u64, total: Option<u64>, unit: &str) {
+	let left = COUNTDOWN.with(|c| {
+		let left = c.get().saturating_sub(1);
+		c.set(left);
+		left
+	});
+	if left > 0 {
+		return;
+	}
+	let cancel = CONTEXT.with(|c| {
+		let mut c = c.borrow_mut();
+		let Some(ctx) = c.as_mut() else {
+			// Nothing to check until the next `run`
+			COUNTDOWN.with(|c| c.set(MAX_STRIDE));
+			return false;
+		};
+		let now = Instant::now();
+		let since = now - ctx.checked;
+		if since < CHECK_INTERVAL / 2 {
+			ctx.stride = (ctx.stride * 2).min(MAX_STRIDE);
+		} else if since > CHECK_INTERVAL * 2 {
+			ctx.stride = (ctx.stride / 2).max(1);
+		}
+		ctx.checked = now;
+		COUNTDOWN.with(|c| c.set(ctx.stride));
+
+		if ctx.visible && ctx.drawn.is_none_or(|t| now - t >= REDRAW) {
+			let mut line = format!("\r\x1b[K{}: {} {}", ctx.label, done, unit);
+			if let Some(total) = total.filter(|&t| t > 0) {
+				line = format!("\r\x1b[K{}: {}/{} {} ({:.0}%)", ctx.label, done,
+					total, unit, done as f64 * 100.0 / total as f64);
+			}
+			let mut stderr = io::stderr();
+			let _ = stderr.write_all(line.as_bytes());
+			let _ = stderr.flush();
+			ctx.drawn = Some(now);
+		}
+		ctx.deadline.is_some_and(|d| now >= d)
+	});
+	// Unlike a panic, this doesn't go through the panic hook, so nothing
+	// gets printed
+	if cancel {
+		panic::resume_unwind(Box::new(Cancelled));
+	}
+}
@@ -0,0 +1,59 @@
+use std::ops::RangeInclusive;
+
+/// A small seeded pseudo-random number generator (SplitMix64).
+///
+/// It is not suitable for anything but producing reproducible test data.
+#[derive(Debug, Clone)]
+pub struct Rng(u64);
+
+impl Rng {
+	pub fn new(seed: u64) -> Self {
+		Self(seed)
+	}
+
+	pub fn next_u64(&mut self) -> u64 {
+		self.0 = self.0.wrapping_add(0x9e3779b97f4a7c15);
+		let mut z = self.0;
+		z = (z ^ (z >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+		z = (z ^ (z >> 27)).wrapping_mul(0x94d049bb133111eb);
+		z ^ (z >> 31)
+	}
+
+	/// A number in `0..n`, which must not be empty.
+	pub fn below(&mut self, n: usize) -> usize {
+		assert!(n > 0, "empty range");
+		((self.next_u64() as u128 * n as u128) >> 64) as usize
+	}
+
+	/// A number in the given inclusive range.
+	pub fn range(&mut self, r: RangeInclusive<i64>) -> i64 {
+		let span = r.end().abs_diff(*r.start()) as usize + 1;
+		r.start() + self.below(span) as i64
+	}
+
+	pub fn chance(&mut self, p: f64) -> bool {
+		let unit = (self.next_u64() >> 11) as f64 / (1u64 << 53) as f64;
+		unit < p
+	}
+
+	pub fn choose<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+		&items[self.below(items.len())]
+	}
+
+	pub fn shuffle<T>(&mut self, items: &mut [T]) {
+		for i in (1..items.len()).rev() {
+			items.swap(i, self.below(i + 1));
+		}
+	}
+
+	/// A string of `len` characters taken from `alphabet`.
+	pub fn string(&mut self, alphabet: &[u8], len: usize) -> String {
+		(0..len).map(|_| *self.choose(alphabet) as char).collect()
+	}
+}
+
+/// Scales one side of a square-ish input so that its area grows by
+/// roughly `scale`.
+pub fn scale_side(base: usize, scale: usize) -> usize {
+	((base as f64) * (scale as f64).sqrt()).round() as usize
+}
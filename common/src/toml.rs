@@ -0,0 +1,290 @@
+use std::collections::BTreeMap;
+use std::fmt;
+
+use crate::ParseError;
+use crate::parse::found_at;
+
+/// A scalar value in a TOML document.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Value {
+	String(String),
+	Integer(i64),
+	Boolean(bool),
+}
+
+impl fmt::Display for Value {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self {
+			Self::String(s) if s.contains('\n') =>
+				write!(f, "\"\"\"\n{}\"\"\"", escape(s)),
+			Self::String(s) => write!(f, "\"{}\"", escape(s)),
+			Self::Integer(n) => write!(f, "{}", n),
+			Self::Boolean(b) => write!(f, "{}", b),
+		}
+	}
+}
+
+fn escape(s: &str) -> String {
+	s.chars()
+		.map(|c| match c {
+			'\\' => "\\\\".to_string(),
+			'"' => "\\\"".to_string(),
+			'\t' => "\\t".to_string(),
+			c => c.to_string(),
+		})
+		.collect()
+}
+
+/// The small subset of TOML used by our local files: `[table]` headers,
+/// `key = value` pairs, comments, and string, integer and boolean values.
+/// Strings may be basic (`"..."`) or multi-line (`"""..."""`).
+///
+/// Keys are stored flattened, so `b` under `[a]` is found as `a.b`.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Document {
+	entries: BTreeMap<String, Value>,
+}
+
+fn is_key_char(c: char) -> bool {
+	c.is_ascii_alphanumeric() || c == '_' || c == '-'
+}
+
+// A cursor over the whole document, since multi-line strings may span
+// several lines
+struct Cursor<'a> {
+	src: &'a str,
+	pos: usize,
+	line: usize,
+	line_start: usize,
+}
+
+impl<'a> Cursor<'a> {
+	fn rest(&self) -> &'a str {
+		&self.src[self.pos..]
+	}
+
+	fn peek(&self) -> Option<char> {
+		self.rest().chars().next()
+	}
+
+	fn bump(&mut self) -> Option<char> {
+		let c = self.peek()?;
+		self.pos += c.len_utf8();
+		if c == '\n' {
+			self.line += 1;
+			self.line_start = self.pos;
+		}
+		Some(c)
+	}
+
+	fn eat(&mut self, tag: &str) -> bool {
+		let found = self.rest().starts_with(tag);
+		if found {
+			tag.chars().for_each(|_| { self.bump(); });
+		}
+		found
+	}
+
+	fn error(&self, expected: impl Into<String>) -> ParseError {
+		let col = self.src[self.line_start..self.pos].chars().count();
+		ParseError::new(col, found_at(self.rest()), expected).at_line(self.line)
+	}
+
+	// Skips spaces and tabs, but not newlines
+	fn skip_blank(&mut self) {
+		while matches!(self.peek(), Some(' ' | '\t')) {
+			self.bump();
+		}
+	}
+
+	// Skips an optional comment and expects the end of the line
+	fn line_end(&mut self) -> Result<(), ParseError> {
+		self.skip_blank();
+		if self.peek() == Some('#') {
+			while !matches!(self.peek(), None | Some('\n')) {
+				self.bump();
+			}
+		}
+		match self.peek() {
+			None => Ok(()),
+			Some('\n') => {
+				self.bump();
+				Ok(())
+			}
+			Some(_) => Err(self.error("end of line")),
+		}
+	}
+
+	fn key(&mut self) -> Result<String, ParseError> {
+		let mut parts = Vec::new();
+		loop {
+			let start = self.pos;
+			while self.peek().is_some_and(is_key_char) {
+				self.bump();
+			}
+			if start == self.pos {
+				return Err(self.error("a key"));
+			}
+			parts.push(&self.src[start..self.pos]);
+			if !self.eat(".") {
+				return Ok(parts.join("."));
+			}
+		}
+	}
+
+	// Reads a string body up to the closing delimiter, handling escapes
+	fn string(&mut self, delim: &str) -> Result<String, ParseError> {
+		let multiline = delim.len() > 1;
+		// A newline right after the opening delimiter is not part of the
+		// string
+		if multiline {
+			self.eat("\n");
+		}
+		let mut out = String::new();
+		loop {
+			if self.eat(delim) {
+				return Ok(out);
+			}
+			match self.peek() {
+				None => return Err(self.error(format!("a closing `{}`", delim))),
+				Some('\n') if !multiline =>
+					return Err(self.error(format!("a closing `{}`", delim))),
+				Some('\\') => {
+					let err = self.error("an escape such as `\\n` or `\\\"`");
+					self.bump();
+					out.push(match self.bump() {
+						Some('n') => '\n',
+						Some('t') => '\t',
+						Some('"') => '"',
+						Some('\\') => '\\',
+						_ => return Err(err),
+					});
+				}
+				Some(_) => out.push(self.bump().unwrap()),
+			}
+		}
+	}
+
+	fn value(&mut self) -> Result<Value, ParseError> {
+		if self.eat("\"\"\"") {
+			return self.string("\"\"\"").map(Value::String);
+		}
+		if self.eat("\"") {
+			return self.string("\"").map(Value::String);
+		}
+		if self.eat("true") {
+			return Ok(Value::Boolean(true));
+		}
+		if self.eat("false") {
+			return Ok(Value::Boolean(false));
+		}
+
+		let start = self.pos;
+		let err = self.error("a string, integer or boolean");
+		if matches!(self.peek(), Some('+' | '-')) {
+			self.bump();
+		}
+		while self.peek().is_some_and(|c| c.is_ascii_digit() || c == '_') {
+			self.bump();
+		}
+		self.src[start..self.pos]
+			.replace('_', "")
+			.parse::<i64>()
+			.map(Value::Integer)
+			.map_err(|_| err)
+	}
+}
+
+impl Document {
+	pub fn new() -> Self {
+		Self::default()
+	}
+
+	pub fn parse(src: &str) -> Result<Self, ParseError> {
+		let mut doc = Self::new();
+		let mut table = String::new();
+		let mut cur = Cursor { src, pos: 0, line: 0, line_start: 0 };
+
+		while cur.peek().is_some() {
+			cur.skip_blank();
+			match cur.peek() {
+				Some('#' | '\n') | None => (),
+				Some('[') => {
+					cur.bump();
+					cur.skip_blank();
+					table = cur.key()?;
+					cur.skip_blank();
+					if !cur.eat("]") {
+						return Err(cur.error("`]`"));
+					}
+				}
+				Some(_) => {
+					let err = cur.error("a key not already defined");
+					let key = match table.is_empty() {
+						true => cur.key()?,
+						false => format!("{}.{}", table, cur.key()?),
+					};
+					if doc.entries.contains_key(&key) {
+						return Err(err);
+					}
+					cur.skip_blank();
+					if !cur.eat("=") {
+						return Err(cur.error("`=`"));
+					}
+					cur.skip_blank();
+					let value = cur.value()?;
+					doc.entries.insert(key, value);
+				}
+			}
+			cur.line_end()?;
+		}
+
+		Ok(doc)
+	}
+
+	pub fn get(&self, key: &str) -> Option<&Value> {
+		self.entries.get(key)
+	}
+
+	pub fn get_str(&self, key: &str) -> Option<&str> {
+		match self.get(key)? {
+			Value::String(s) => Some(s),
+			_ => None,
+		}
+	}
+
+	pub fn get_int(&self, key: &str) -> Option<i64> {
+		match self.get(key)? {
+			Value::Integer(n) => Some(*n),
+			_ => None,
+		}
+	}
+
+	pub fn set(&mut self, key: impl Into<String>, value: Value) {
+		self.entries.insert(key.into(), value);
+	}
+
+	pub fn iter(&self) -> impl Iterator<Item=(&str, &Value)> {
+		self.entries.iter().map(|(k, v)| (k.as_str(), v))
+	}
+}
+
+// Root keys come first, followed by one `[table]` per key prefix
+impl fmt::Display for Document {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		let mut entries: Vec<_> = self.iter()
+			.map(|(k, v)| (k.rsplit_once('.').unwrap_or(("", k)), v))
+			.collect();
+		entries.sort_by_key(|((table, _), _)| (!table.is_empty(), *table));
+
+		let mut current = "";
+		for ((table, key), value) in entries {
+			if table != current {
+				writeln!(f, "\n[{}]", table)?;
+				current = table;
+			}
+			writeln!(f, "{} = {}", key, value)?;
+		}
+		Ok(())
+	}
+}
@@ -0,0 +1,70 @@
+use common::input::normalize;
+
+fn fixed(raw: &str) -> String {
+	normalize(raw).0
+}
+
+fn fixes(raw: &str) -> Vec<String> {
+	normalize(raw).1
+}
+
+#[test]
+fn clean_input_is_untouched() {
+	assert_eq!(normalize("a\nb\n"), ("a\nb\n".to_string(), vec![]));
+	assert_eq!(normalize("a\n\nb\n"), ("a\n\nb\n".to_string(), vec![]));
+	assert_eq!(normalize(""), (String::new(), vec![]));
+}
+
+#[test]
+fn byte_order_mark_is_removed() {
+	assert_eq!(fixed("\u{feff}a\n"), "a\n");
+	assert_eq!(fixes("\u{feff}a\n"), ["removed a UTF-8 byte order mark"]);
+	// Only at the very start
+	assert_eq!(fixed("a\u{feff}\n"), "a\u{feff}\n");
+}
+
+#[test]
+fn crlf_is_converted() {
+	assert_eq!(fixed("a\r\nb\r\n"), "a\nb\n");
+	assert_eq!(fixes("a\r\nb\r\n"), ["converted CRLF line endings on 2 lines, starting at line 1"]);
+	assert_eq!(fixes("a\nb\r\n"), ["converted CRLF line endings on line 2"]);
+}
+
+#[test]
+fn lone_cr_is_converted() {
+	assert_eq!(fixed("a\rb\r"), "a\nb\n");
+	assert_eq!(fixes("a\rb\r"), ["converted CR line endings on 2 lines, starting at line 1"]);
+	assert_eq!(fixed("a\r\r\nb\n"), "a\n\nb\n");
+	assert_eq!(fixes("a\nb\rc\n"), ["converted CR line endings on line 2"]);
+}
+
+#[test]
+fn trailing_whitespace_is_removed() {
+	assert_eq!(fixed("a \nb\t\nc\n"), "a\nb\nc\n");
+	assert_eq!(fixes("a \nb\t\nc\n"), ["removed trailing whitespace on 2 lines, starting at line 1"]);
+	// Leading whitespace is significant, as in day 5's crates
+	assert_eq!(fixed("    [D]\n"), "    [D]\n");
+	// A line of only whitespace becomes blank
+	assert_eq!(fixed("a\n  \nb\n"), "a\n\nb\n");
+}
+
+#[test]
+fn final_newline_is_made_single() {
+	assert_eq!(fixed("a\nb"), "a\nb\n");
+	assert_eq!(fixes("a\nb"), ["added a missing final newline"]);
+	assert_eq!(fixed("a\n\n\n"), "a\n");
+	assert_eq!(fixes("a\n\n\n"), ["removed 2 blank lines at the end"]);
+	assert_eq!(fixed("\n\n"), "");
+}
+
+#[test]
+fn fixes_combine() {
+	let (inp, fixes) = normalize("\u{feff}a \r\nb\r\n\r\n");
+	assert_eq!(inp, "a\nb\n");
+	assert_eq!(fixes, [
+		"removed a UTF-8 byte order mark",
+		"converted CRLF line endings on 3 lines, starting at line 1",
+		"removed trailing whitespace on line 1",
+		"removed 1 blank lines at the end",
+	]);
+}
@@ -0,0 +1,35 @@
+use common::{Json, ToJson};
+
+fn json(s: &str) -> String {
+	s.to_json().to_string()
+}
+
+#[test]
+fn quotes_and_backslashes_are_escaped() {
+	assert_eq!(json("say \"hi\""), r#""say \"hi\"""#);
+	assert_eq!(json("C:\\dir\\"), r#""C:\\dir\\""#);
+	assert_eq!(json("\\\""), r#""\\\"""#);
+	assert_eq!(json("'single'"), r#""'single'""#);
+}
+
+#[test]
+fn control_characters_are_escaped() {
+	assert_eq!(json("a\nb\r\tc"), r#""a\nb\r\tc""#);
+	assert_eq!(json("\0"), r#""\u0000""#);
+	assert_eq!(json("\u{1b}[0m"), r#""\u001b[0m""#);
+	assert_eq!(json("\u{7f}"), r#""\u007f""#);
+	assert_eq!(json("\u{85}"), r#""\u0085""#);
+}
+
+#[test]
+fn non_ascii_is_kept() {
+	assert_eq!(json("naïve → ✓"), "\"naïve → ✓\"");
+	assert_eq!(json("🎄"), "\"🎄\"");
+	assert_eq!(json("█░"), "\"█░\"");
+}
+
+#[test]
+fn object_keys_are_escaped() {
+	let obj = Json::object([("a\"b\n", 1u8.to_json()), ("é", "\\".to_json())]);
+	assert_eq!(obj.to_string(), r#"{"a\"b\n":1,"é":"\\"}"#);
+}
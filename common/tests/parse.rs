@@ -0,0 +1,30 @@
+use common::Scanner;
+
+#[test]
+fn error_reports_next_word() {
+	let mut s = Scanner::new("move 1 from x");
+	s.tag("move ").unwrap();
+	let e = s.tag("from").unwrap_err();
+	assert_eq!(e.found, "1");
+	assert_eq!(e.to_string(), "line 1, column 6: expected `from`, found `1`");
+}
+
+#[test]
+fn error_reports_end_of_line() {
+	let mut s = Scanner::new("12");
+	s.number::<u32>().unwrap();
+	let e = s.tag(" ").unwrap_err();
+	assert_eq!(e.to_string(), "line 1, column 3: expected a space, found end of line");
+}
+
+#[test]
+fn error_reports_stray_whitespace() {
+	let mut s = Scanner::new("12\r");
+	s.number::<u32>().unwrap();
+	let e = s.end().unwrap_err();
+	assert_eq!(e.found, "\r");
+	assert_eq!(e.to_string(), "line 1, column 3: expected end of line, found `\\r`");
+
+	let e = Scanner::new("\tx").tag("x").unwrap_err();
+	assert_eq!(e.to_string(), "line 1, column 1: expected `x`, found `\\t`");
+}
@@ -0,0 +1,53 @@
+use std::panic;
+use std::thread;
+use std::time::{Duration, Instant};
+
+use common::progress::{self, TimedOut};
+
+// The budget is global, so everything depending on it is in one test
+#[test]
+fn budget_cancels_reporting_loops() {
+	progress::set_budget(Some(Duration::from_millis(50)));
+	let start = Instant::now();
+	let res = progress::run("spin", || {
+		let mut done = 0;
+		loop {
+			done += 1;
+			progress::report(done, None, "steps");
+		}
+	});
+	let TimedOut(ran) = res.unwrap_err();
+	assert!(ran >= Duration::from_millis(50) && ran <= start.elapsed(), "{:?}", ran);
+	assert!(start.elapsed() < Duration::from_secs(5));
+
+	// Coarse loops reporting rarely are stopped soon after the budget too,
+	// even when they report fewer times than any fixed stride
+	let start = Instant::now();
+	let res = progress::run("rounds", || for round in 0..20 {
+		thread::sleep(Duration::from_millis(20));
+		progress::report(round, Some(20), "rounds");
+	});
+	let TimedOut(ran) = res.unwrap_err();
+	assert!(ran >= Duration::from_millis(50) && ran < Duration::from_millis(200), "{:?}", ran);
+	assert!(start.elapsed() < Duration::from_millis(200));
+	assert_eq!(TimedOut(Duration::from_millis(61)).to_string(), "timed out after 0.1s");
+
+	// Loops finishing in time keep their result
+	assert_eq!(progress::run("sum", || (0..10_000u64)
+		.inspect(|&i| progress::report(i, Some(10_000), "steps"))
+		.sum::<u64>()), Ok(49_995_000));
+
+	// Real panics are passed on rather than taken for timeouts
+	let res = panic::catch_unwind(|| progress::run("panic", || panic!("boom")));
+	assert!(res.is_err());
+
+	progress::set_budget(None);
+	assert_eq!(progress::run("done", || 1), Ok(1));
+}
+
+#[test]
+fn reports_outside_run_are_ignored() {
+	for i in 0..10_000 {
+		progress::report(i, Some(10_000), "steps");
+	}
+}
@@ -0,0 +1,102 @@
+use common::toml::{Document, Value};
+
+fn round_trip(entries: &[(&str, Value)]) {
+	let mut doc = Document::new();
+	for (key, value) in entries {
+		doc.set(*key, value.clone());
+	}
+	let text = doc.to_string();
+	let parsed = Document::parse(&text)
+		.unwrap_or_else(|e| panic!("{}\n{}", e.snippet(&text), text));
+	assert_eq!(parsed, doc, "{}", text);
+}
+
+fn string(s: &str) -> Value {
+	Value::String(s.to_string())
+}
+
+fn error_line(src: &str) -> usize {
+	Document::parse(src).expect_err(src).line
+}
+
+#[test]
+fn integers_round_trip() {
+	round_trip(&[
+		("zero", Value::Integer(0)),
+		("negative", Value::Integer(-42)),
+		("min", Value::Integer(i64::MIN)),
+		("max", Value::Integer(i64::MAX)),
+	]);
+	let doc = Document::parse("n = +1_000\nm = -7\n").unwrap();
+	assert_eq!(doc.get_int("n"), Some(1000));
+	assert_eq!(doc.get_int("m"), Some(-7));
+}
+
+#[test]
+fn basic_strings_round_trip() {
+	round_trip(&[
+		("empty", string("")),
+		("quotes", string("say \"hi\"")),
+		("backslash", string("C:\\dir\\")),
+		("tab", string("a\tb")),
+		("unicode", string("naïve → ✓")),
+		("hash", string("# not a comment")),
+		("ends_in_quote", string("quoted \"")),
+		("only_quote", string("\"")),
+	]);
+	let doc = Document::parse(r#"s = "a\"b\\c\td\n""#).unwrap();
+	assert_eq!(doc.get_str("s"), Some("a\"b\\c\td\n"));
+}
+
+#[test]
+fn multi_line_strings_round_trip() {
+	// The shape of a day 10 part 2 answer
+	let crt = "\
+###..#....###...##..####.###...##..#....
+#..#.#....#..#.#..#.#....#..#.#..#.#....
+###..#....#..#.#..#.###..###..#....#....
+#..#.#....###..####.#....#..#.#.##.#....
+#..#.#....#....#..#.#....#..#.#..#.#....
+###..####.#....#..#.#....###...###.####.
+";
+	round_trip(&[
+		("day10.part2", string(crt)),
+		("no_final_newline", string("a\nb")),
+		("leading_newline", string("\nfirst line is empty")),
+		("ends_in_quote", string("line\n\"")),
+		("triple_quote", string("\"\"\"\n\"\"\"")),
+	]);
+	let doc = Document::parse("s = \"\"\"\nab\ncd\n\"\"\"\n").unwrap();
+	assert_eq!(doc.get_str("s"), Some("ab\ncd\n"));
+}
+
+#[test]
+fn tables_round_trip() {
+	round_trip(&[
+		("root", Value::Boolean(true)),
+		("day1.part1", Value::Integer(1)),
+		("day1.part2", string("two")),
+		("day12.part1", Value::Boolean(false)),
+		("a.b.c", Value::Integer(3)),
+	]);
+	let doc = Document::parse("x = 1\n[day5]  # comment\npart1 = \"CMZ\"\n\n[a.b]\nc = 2\n").unwrap();
+	assert_eq!(doc.get_int("x"), Some(1));
+	assert_eq!(doc.get_str("day5.part1"), Some("CMZ"));
+	assert_eq!(doc.get_int("a.b.c"), Some(2));
+	assert_eq!(doc.get("part1"), None);
+}
+
+#[test]
+fn malformed_documents_are_rejected() {
+	assert_eq!(error_line("a = 1\nb 2\n"), 1);
+	assert_eq!(error_line("a = \"open\n"), 0);
+	assert_eq!(error_line("a = \"\"\"\nnever closed\n"), 2);
+	assert_eq!(error_line("a = \"bad \\q escape\"\n"), 0);
+	assert_eq!(error_line("a = 1\na = 2\n"), 1);
+	assert_eq!(error_line("[table\nb = 1\n"), 0);
+	assert_eq!(error_line("a = 1 2\n"), 0);
+	assert_eq!(error_line("a = 99999999999999999999\n"), 0);
+	assert_eq!(error_line("a = yes\n"), 0);
+	assert_eq!(error_line("= 1\n"), 0);
+	assert!(Document::parse("[a]\nb = 1\n[a]\nb = 2\n").is_err());
+}
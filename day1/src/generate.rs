@@ -0,0 +1,14 @@
+use common::{Generate, Rng};
+
+use crate::Day1;
+
+impl Generate for Day1 {
+    fn generate(rng: &mut Rng, scale: usize) -> String {
+        (0..250 * scale)
+            .map(|_| (0..rng.range(1..=15))
+                .map(|_| format!("{}\n", rng.range(1000..=60000)))
+                .collect::<String>())
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
@@ -0,0 +1,67 @@
+use common::{Error, Json, ParseError, Params, Scanner, Solution, ToJson};
+
+mod generate;
+
+fn calories(elves: &[Vec<u64>]) -> Vec<u64> {
+    elves.iter()
+        .map(|c| c.iter().sum::<u64>())
+        .collect::<Vec<_>>()
+}
+
+pub struct Elves {
+    pub calories: Vec<Vec<u64>>,
+    // How many of the elves carrying the most part 2 adds up
+    pub top: usize,
+}
+
+impl ToJson for Elves {
+    fn to_json(&self) -> Json {
+        Json::object([
+            ("calories", self.calories.to_json()),
+            ("top", self.top.to_json()),
+        ])
+    }
+}
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    type Input = Elves;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(inp: &str) -> Result<Self::Input, ParseError> {
+        common::blocks(inp)
+            .map(|(start, e)| common::parse_lines(e, |c| {
+                    let mut sc = Scanner::new(c);
+                    let calories = sc.number::<u64>()?;
+                    sc.end()?;
+                    Ok(calories)
+                })
+                .map_err(|err| err.at_line(start)))
+            .collect::<Result<Vec<_>, _>>()
+            .and_then(|calories| match calories.is_empty() {
+                true => Err(ParseError::new(0, "", "the calories of at least one elf")),
+                false => Ok(Elves { calories, top: 3 }),
+            })
+    }
+
+    // There is always an elf, as parsing makes sure
+    fn part1(elves: &Self::Input) -> u64 {
+        calories(&elves.calories).into_iter().max().unwrap()
+    }
+
+    fn part2(elves: &Self::Input) -> u64 {
+        let mut calories = calories(&elves.calories);
+        calories.sort();
+        calories.iter()
+            .rev()
+            .take(elves.top)
+            .sum::<u64>()
+    }
+
+    fn configure(elves: &mut Self::Input, params: &Params) -> Result<(), Error> {
+        params.expect(&["top"])?;
+        params.apply("top", 1, &mut elves.top)
+    }
+}
@@ -1,30 +1,20 @@
-use std::fs;
-use std::io;
+use std::error::Error;
 
-fn main() -> io::Result<()> {
-    let inp = fs::read_to_string("./input.txt")?;
+use common::Solution;
+use day1::Day1;
 
-    let elves = inp.split("\n\n")
-        .map(|e| e.split('\n')
-            .filter(|c| !c.is_empty())
-            .map(|c| c.parse::<u64>())
-            .collect::<Result<Vec<_>, _>>())
-        .collect::<Result<Vec<_>, _>>()
-        .unwrap();
+fn main() -> Result<(), Box<dyn Error>> {
+    let inp = common::input::read_or_exit();
+    let mut elves = common::parse_or_exit::<Day1>(&inp);
+    common::configure_or_exit::<Day1>(1, &mut elves);
 
-    let mut calories = elves.iter()
-        .map(|c| c.iter().sum::<u64>())
-        .collect::<Vec<_>>();
+    if common::json_requested() {
+        common::print_json::<Day1>(1, &elves);
+        return Ok(());
+    }
 
-    println!("Part 1: {}", calories.iter().max().unwrap());
-
-    calories.sort();
-    let top = calories.iter()
-        .rev()
-        .take(3)
-        .sum::<u64>();
-
-    println!("Part 2: {}", top);
+    println!("Part 1: {}", Day1::part1(&elves));
+    println!("Part 2: {}", Day1::part2(&elves));
 
     Ok(())
 }
@@ -0,0 +1,22 @@
+use common::Solution;
+use day1::Day1;
+
+const EXAMPLE: &str = include_str!("../example.txt");
+
+#[test]
+fn part1() {
+    let input = Day1::parse(EXAMPLE).unwrap();
+    assert_eq!(Day1::part1(&input), 24000);
+}
+
+#[test]
+fn part2() {
+    let input = Day1::parse(EXAMPLE).unwrap();
+    assert_eq!(Day1::part2(&input), 45000);
+}
+
+#[test]
+fn no_elves() {
+    assert!(Day1::parse("").is_err());
+    assert!(Day1::parse("\n\n").is_err());
+}
@@ -0,0 +1,32 @@
+// The 4x6 capital letters the CRT draws, one string per letter with its
+// rows concatenated
+const GLYPHS: &[(char, &str)] = &[
+	('A', ".##.#..##..######..##..#"),
+	('B', "###.#..####.#..##..####."),
+	('C', ".##.#..##...#...#..#.##."),
+	('E', "#####...###.#...#...####"),
+	('F', "#####...###.#...#...#..."),
+	('G', ".##.#..##...#.###..#.###"),
+	('H', "#..##..######..##..##..#"),
+	('I', ".###..#...#...#...#..###"),
+	('J', "..##...#...#...##..#.##."),
+	('K', "#..##.#.##..#.#.#.#.#..#"),
+	('L', "#...#...#...#...#...####"),
+	('O', ".##.#..##..##..##..#.##."),
+	('P', "###.#..##..####.#...#..."),
+	('R', "###.#..##..####.#.#.#..#"),
+	('S', ".####...#....##....####."),
+	('U', "#..##..##..##..##..#.##."),
+	('Y', "#...#....#.#..#...#...#."),
+	('Z', "####...#..#..#..#...####"),
+];
+
+pub const WIDTH: usize = 4;
+pub const HEIGHT: usize = 6;
+
+// Reads the letter drawn in the given rows, or `?` if it is not known
+pub fn decode(glyph: &str) -> char {
+	GLYPHS.iter()
+		.find(|(_, g)| *g == glyph)
+		.map_or('?', |(c, _)| *c)
+}
@@ -0,0 +1,33 @@
+use common::{Generate, Rng};
+
+use crate::Day10;
+
+impl Generate for Day10 {
+	// The program runs for at least as many cycles as the CRT has pixels,
+	// and keeps the sprite on screen most of the time
+	fn generate(rng: &mut Rng, scale: usize) -> String {
+		let mut out = String::new();
+		let mut x = 1;
+		let mut cycles = 0;
+		while cycles < 240 * scale {
+			match rng.chance(0.3) {
+				true => {
+					out += "noop\n";
+					cycles += 1;
+				}
+				false => {
+					let v = loop {
+						let v = rng.range(-20..=20);
+						if v != 0 && (-1..=40).contains(&(x + v)) {
+							break v;
+						}
+					};
+					x += v;
+					out += &format!("addx {}\n", v);
+					cycles += 2;
+				}
+			}
+		}
+		out
+	}
+}
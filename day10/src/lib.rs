@@ -0,0 +1,242 @@
+use std::str::{self, FromStr};
+use std::fmt::{self, Write};
+
+use common::{Error, Json, ParseError, Params, Scanner, Solution, ToJson};
+use grid::Grid;
+
+mod font;
+mod generate;
+mod replay;
+
+pub enum Instruction {
+	Noop,
+	AddX(i64),
+}
+
+impl Instruction {
+	fn num_cycles(&self) -> usize {
+		match self {
+			Self::Noop => 1,
+			Self::AddX(_) => 2,
+		}
+	}
+}
+
+impl ToJson for Instruction {
+	fn to_json(&self) -> Json {
+		match self {
+			Self::Noop => Json::object([("op", "noop".to_json())]),
+			Self::AddX(n) => Json::object([
+				("op", "addx".to_json()),
+				("value", n.to_json()),
+			]),
+		}
+	}
+}
+
+impl str::FromStr for Instruction {
+	type Err = ParseError;
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		let mut sc = Scanner::new(s);
+		let ins = match sc.word()? {
+			"addx" => {
+				sc.tag(" ")?;
+				Self::AddX(sc.number::<i64>()?)
+			}
+			"noop" => Self::Noop,
+			ins => return Err(ParseError::new(0, ins, "`addx` or `noop`")),
+		};
+		sc.end()?;
+		Ok(ins)
+	}
+}
+
+#[derive(Clone, Copy)]
+pub struct Cpu {
+	x: i64
+}
+
+impl Cpu {
+	fn new() -> Self {
+		Self { x: 1 }
+	}
+
+	fn exec(&mut self, instructions: &[Instruction]) -> CycleState {
+		let snapshots = instructions.iter()
+			.flat_map(|ins| {
+				let vals = vec![*self; ins.num_cycles()];
+				match ins {
+					Instruction::Noop => (),
+					Instruction::AddX(v) => self.x += v,
+				};
+				vals
+			}).collect();
+		CycleState { snapshots }
+	}
+}
+
+pub struct CycleState {
+	snapshots: Vec<Cpu>
+}
+
+impl CycleState {
+	fn signal_strength(&self, n: usize) -> i64 {
+		self.x(n - 1) * (n as i64)
+	}
+
+	fn x(&self, n: usize) -> i64 {
+		self.snapshots[n].x
+	}
+
+	fn len(&self) -> usize {
+		self.snapshots.len()
+	}
+}
+
+#[derive(Copy, Clone, Default)]
+pub enum Pixel {
+	Lit,
+	#[default]
+	Dark,
+}
+
+impl fmt::Display for Pixel {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self {
+			Self::Lit => f.write_char('#'),
+			Self::Dark => f.write_char('.'),
+		}
+	}
+}
+
+#[allow(clippy::upper_case_acronyms)]
+pub struct CRT {
+	pixels: Grid<Pixel>,
+}
+
+impl CRT {
+	fn new(rows: usize, cols: usize) -> Self {
+		Self {
+			pixels: Grid::new(cols, rows, Pixel::default()),
+		}
+	}
+
+	fn exec(&mut self, cycles: &CycleState) -> &Self {
+		self.exec_until(cycles, cycles.len())
+	}
+
+	// Draws the pixels of the first `n` cycles only. Pixels past the end of
+	// the program stay dark.
+	fn exec_until(&mut self, cycles: &CycleState, n: usize) -> &Self {
+		let cols = self.pixels.width();
+		for (i, j) in self.pixels.positions().take(n.min(cycles.len())) {
+			let x = cycles.x(i * cols + j);
+			let ipos = j as i64;
+			if x - 1 <= ipos && ipos <= x + 1 {
+				self.pixels[(i, j)] = Pixel::Lit;
+			}
+		}
+		self
+	}
+}
+
+impl CRT {
+	pub fn rows(&self) -> Vec<String> {
+		self.pixels.rows()
+			.map(|row| row.iter().map(|p| p.to_string()).collect())
+			.collect()
+	}
+
+	// Reads the capital letters on screen, with a one pixel gap between
+	// them
+	pub fn text(&self) -> String {
+		let rows = self.rows();
+		if rows.len() != font::HEIGHT {
+			return String::new();
+		}
+		(0..self.pixels.width() / (font::WIDTH + 1))
+			.map(|i| {
+				let start = i * (font::WIDTH + 1);
+				let glyph: String = rows.iter()
+					.map(|row| &row[start..start + font::WIDTH])
+					.collect();
+				font::decode(&glyph)
+			})
+			.collect()
+	}
+}
+
+impl ToJson for CRT {
+	fn to_json(&self) -> Json {
+		Json::object([
+			("rows", self.rows().to_json()),
+			("text", Json::String(self.text())),
+		])
+	}
+}
+
+impl fmt::Display for CRT {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		self.pixels.fmt(f)
+	}
+}
+
+// The most rows or columns a screen may have
+const MAX_SIDE: usize = 1000;
+
+pub struct Program {
+	pub ins: Vec<Instruction>,
+	// Size of the screen drawn in part 2
+	pub rows: usize,
+	pub cols: usize,
+}
+
+impl ToJson for Program {
+	fn to_json(&self) -> Json {
+		Json::object([
+			("ins", self.ins.to_json()),
+			("rows", self.rows.to_json()),
+			("cols", self.cols.to_json()),
+		])
+	}
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+	type Input = Program;
+	type Answer1 = i64;
+	type Answer2 = CRT;
+
+	fn parse(inp: &str) -> Result<Self::Input, ParseError> {
+		common::parse_lines(inp, Instruction::from_str)
+			.map(|ins| Program { ins, rows: 6, cols: 40 })
+	}
+
+	fn part1(prog: &Self::Input) -> i64 {
+		let cycles = Cpu::new().exec(&prog.ins);
+		(20..).step_by(40)
+			.take_while(|i| *i < cycles.len())
+			.map(|i| cycles.signal_strength(i))
+			.sum()
+	}
+
+	fn part2(prog: &Self::Input) -> CRT {
+		let cycles = Cpu::new().exec(&prog.ins);
+		let mut crt = CRT::new(prog.rows, prog.cols);
+		crt.exec(&cycles);
+		crt
+	}
+
+	fn configure(prog: &mut Self::Input, params: &Params) -> Result<(), Error> {
+		params.expect(&["rows", "cols"])?;
+		params.apply("rows", 1, &mut prog.rows)?;
+		params.apply("cols", 1, &mut prog.cols)?;
+		// The screen is allocated whole, however short the program
+		match (prog.rows <= MAX_SIDE, prog.cols <= MAX_SIDE) {
+			(false, _) => Err(format!("rows must be at most {}", MAX_SIDE).into()),
+			(_, false) => Err(format!("cols must be at most {}", MAX_SIDE).into()),
+			_ => Ok(()),
+		}
+	}
+}
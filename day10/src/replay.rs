@@ -0,0 +1,47 @@
+use visual::{Simulation, Visualize};
+
+use crate::{CRT, Cpu, CycleState, Day10};
+
+// The screen being drawn one cycle at a time, along with the sprite
+struct Replay {
+	cycles: CycleState,
+	rows: usize,
+	cols: usize,
+	part: u8,
+}
+
+impl Simulation for Replay {
+	fn steps(&self) -> usize {
+		self.cycles.len().min(self.rows * self.cols)
+	}
+
+	fn render(&self, step: usize) -> String {
+		let mut crt = CRT::new(self.rows, self.cols);
+		crt.exec_until(&self.cycles, step);
+		// X during the next cycle, or its last value once the program is over
+		let x = self.cycles.snapshots.get(step)
+			.or(self.cycles.snapshots.last())
+			.map_or(1, |cpu| cpu.x);
+		let sprite: String = (0..self.cols as i64)
+			.map(|col| if (x - 1..=x + 1).contains(&col) { '#' } else { '.' })
+			.collect();
+
+		let mut out = format!("{}\n\nsprite\n{}\n\ncycle {}, X = {}",
+			crt, sprite, step + 1, x);
+		// Part 1 samples the signal strength every 40 cycles from the 20th
+		if self.part == 1 {
+			let strength: i64 = (20..=step).step_by(40)
+				.map(|i| self.cycles.signal_strength(i))
+				.sum();
+			out.push_str(&format!(", signal strength so far {}", strength));
+		}
+		out
+	}
+}
+
+impl Visualize for Day10 {
+	fn simulation(prog: &Self::Input, part: u8) -> Box<dyn Simulation> {
+		let cycles = Cpu::new().exec(&prog.ins);
+		Box::new(Replay { cycles, rows: prog.rows, cols: prog.cols, part })
+	}
+}
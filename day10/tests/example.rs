@@ -0,0 +1,39 @@
+use common::{Params, Solution};
+use day10::Day10;
+
+const EXAMPLE: &str = include_str!("../example.txt");
+
+const PICTURE: &str = "\
+##..##..##..##..##..##..##..##..##..##..
+###...###...###...###...###...###...###.
+####....####....####....####....####....
+#####.....#####.....#####.....#####.....
+######......######......######......####
+#######.......#######.......#######.....";
+
+#[test]
+fn part1() {
+	let input = Day10::parse(EXAMPLE).unwrap();
+	assert_eq!(Day10::part1(&input), 13140);
+}
+
+#[test]
+fn part2() {
+	let input = Day10::parse(EXAMPLE).unwrap();
+	assert_eq!(Day10::part2(&input).to_string(), PICTURE);
+}
+
+#[test]
+fn screen_size_is_bounded() {
+	for arg in ["rows=0", "rows=1000000000000", "cols=1001"] {
+		let mut input = Day10::parse(EXAMPLE).unwrap();
+		let mut params = Params::new();
+		params.set_arg(arg).unwrap();
+		assert!(Day10::configure(&mut input, &params).is_err(), "{}", arg);
+	}
+	let mut input = Day10::parse(EXAMPLE).unwrap();
+	let mut params = Params::new();
+	params.set_arg("cols=1000").unwrap();
+	Day10::configure(&mut input, &params).unwrap();
+	assert_eq!(input.cols, 1000);
+}
@@ -0,0 +1,70 @@
+use common::Solution;
+use day10::Day10;
+
+// Letters as the CRT draws them, 4x6 with rows concatenated
+const Z: &str = "####...#..#..#..#...####";
+const E: &str = "#####...###.#...#...####";
+const B: &str = "###.#..####.#..##..####.";
+const R: &str = "###.#..##..####.#.#.#..#";
+const A: &str = ".##.#..##..######..##..#";
+// Not a letter of the font
+const UNKNOWN: &str = "#..#.##..##..##..##.#..#";
+
+// The pixels of a screen showing the given glyphs, with a one pixel gap
+// after each
+fn screen(glyphs: &[&str]) -> Vec<bool> {
+	(0..6)
+		.flat_map(|row| glyphs.iter()
+			.flat_map(move |g| g[row * 4..row * 4 + 4].chars().chain(['.'])))
+		.map(|c| c == '#')
+		.collect()
+}
+
+// A program drawing `pixels`, two at a time with one `addx` each, which
+// puts the sprite where it lights just the pixels of the pair that are lit
+fn program(pixels: &[bool], cols: usize) -> String {
+	let lights = |x: i64, i: usize| (x - (i % cols) as i64).abs() <= 1;
+	let mut x = 1;
+	let mut out = String::new();
+	for (i, pair) in pixels.chunks(2).enumerate() {
+		let fits = |x| (0..2).all(|k| lights(x, i * 2 + k) == pair[k]);
+		// The sprite starts at 1
+		if i == 0 {
+			assert!(fits(x), "the first two pixels must be lit");
+			continue;
+		}
+		let target = (-2..cols as i64 + 2).find(|&x| fits(x)).unwrap();
+		out += &format!("addx {}\n", target - x);
+		x = target;
+	}
+	// Each `addx` moves the sprite for the pair after the one it draws, so
+	// the last pair needs two more cycles
+	out + "noop\nnoop\n"
+}
+
+fn text(glyphs: &[&str]) -> String {
+	let cols = glyphs.len() * 5;
+	let mut prog = Day10::parse(&program(&screen(glyphs), cols)).unwrap();
+	prog.cols = cols;
+	Day10::part2(&prog).text()
+}
+
+#[test]
+fn decodes_known_letters() {
+	assert_eq!(text(&[Z, E, B, R, A]), "ZEBRA");
+	assert_eq!(text(&[E]), "E");
+}
+
+#[test]
+fn unknown_glyphs_read_as_question_marks() {
+	assert_eq!(text(&[B, UNKNOWN, A]), "B?A");
+	assert_eq!(text(&[Z, &".".repeat(24)]), "Z?");
+}
+
+#[test]
+fn screens_of_another_height_have_no_text() {
+	let mut prog = Day10::parse(&program(&screen(&[E]), 5)).unwrap();
+	prog.cols = 5;
+	prog.rows = 5;
+	assert_eq!(Day10::part2(&prog).text(), "");
+}
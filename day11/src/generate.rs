@@ -0,0 +1,89 @@
+use std::collections::VecDeque;
+
+use common::{Generate, Rng};
+
+use crate::{Day11, Monkeys, Worry};
+use crate::operator::Operator;
+
+// Divisors are distinct primes, whose product must fit in a `Worry` for
+// part 2 to reduce worry levels by it, which allows 15 monkeys at most
+const PRIMES: [usize; 15] = [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37, 41, 43, 47];
+
+// The size in bytes of a real input of 8 monkeys, and roughly how much each
+// monkey takes up besides its items, which take 4 bytes each
+const REAL_SIZE: usize = 1256;
+const MONKEY_SIZE: usize = 140;
+
+fn candidate(rng: &mut Rng, scale: usize) -> String {
+	// Once out of monkeys, the rest of the size goes to their items
+	let num = (8 * scale).min(PRIMES.len());
+	let max_items = ((REAL_SIZE * scale).saturating_sub(MONKEY_SIZE * num) / (2 * num)).max(2);
+	let mut divs = PRIMES.to_vec();
+	rng.shuffle(&mut divs);
+	let square = rng.below(num);
+
+	(0..num)
+		.map(|i| {
+			let items = (0..rng.range(1..=max_items as i64))
+				.map(|_| rng.range(50..=99).to_string())
+				.collect::<Vec<_>>()
+				.join(", ");
+			let op = match (i == square, rng.chance(0.5)) {
+				(true, _) => "* old".to_string(),
+				(false, true) => format!("+ {}", rng.range(1..=8)),
+				(false, false) => format!("* {}", rng.range(2..=19)),
+			};
+			let if_true = (i + 1 + rng.below(num - 1)) % num;
+			let if_false = loop {
+				let dst = rng.below(num);
+				if dst != i && dst != if_true {
+					break dst;
+				}
+			};
+			format!("Monkey {}:\n  Starting items: {}\n  \
+				Operation: new = old {}\n  Test: divisible by {}\n    \
+				If true: throw to monkey {}\n    \
+				If false: throw to monkey {}\n",
+				i, items, op, divs[i], if_true, if_false)
+		})
+		.collect::<Vec<_>>()
+		.join("\n")
+}
+
+// Whether worry levels stay within a `Worry` over the rounds of part 1,
+// where they cannot be reduced
+fn fits_part1(monkeys: &Monkeys) -> bool {
+	let mut items: Vec<VecDeque<Worry>> = monkeys.iter()
+		.map(|m| m.items().collect())
+		.collect();
+
+	for _ in 0..20 {
+		for (src, monkey) in monkeys.iter().enumerate() {
+			while let Some(item) = items[src].pop_front() {
+				let item = match monkey.operator() {
+					Operator::Add(v) => item.checked_add(v),
+					Operator::Mul(v) => item.checked_mul(v),
+					Operator::Pow(_) => item.checked_mul(item),
+				};
+				let Some(item) = item.map(|i| i / 3) else {
+					return false;
+				};
+				items[monkey.test(item)].push_back(item);
+			}
+		}
+	}
+	true
+}
+
+impl Generate for Day11 {
+	// Real inputs keep part 1 worry levels in range, so candidates that
+	// would overflow are thrown away
+	fn generate(rng: &mut Rng, scale: usize) -> String {
+		loop {
+			let inp = candidate(rng, scale);
+			if fits_part1(&inp.parse().unwrap()) {
+				return inp;
+			}
+		}
+	}
+}
@@ -0,0 +1,180 @@
+use std::str::FromStr;
+
+use common::{Error, Found, Json, ParseError, Params, Solution, ToJson};
+use common::progress;
+
+mod generate;
+pub mod monkey;
+pub mod operator;
+pub mod reference;
+mod replay;
+pub mod test;
+
+pub type Worry = u64;
+
+#[derive(Clone)]
+pub struct Monkeys(Vec<monkey::Monkey>);
+
+impl Monkeys {
+	fn len(&self) -> usize {
+		self.0.len()
+	}
+
+	pub fn iter(&self) -> impl Iterator<Item=&monkey::Monkey> {
+		self.0.iter()
+	}
+
+	// How many items each monkey inspects, unless a worry level outgrows
+	// `Worry`, which only happens when worry drops after every inspection
+	pub fn emulate(&mut self, rounds: usize, div: Worry) -> Option<Vec<usize>> {
+		self.emulate_with(rounds, div, |_, _| ())
+	}
+
+	// Like `emulate()`, calling `on_round` with the monkeys and how many
+	// items each has inspected after every round
+	pub fn emulate_with(&mut self, rounds: usize, div: Worry,
+		mut on_round: impl FnMut(&Self, &[usize])) -> Option<Vec<usize>>
+	{
+		let mut inspected = vec![0; self.len()];
+		let modulo = self.0.iter()
+			.try_fold(1 as Worry, |acc, m| acc.checked_mul(m.div()));
+
+		for round in 0..rounds {
+			progress::report(round as u64, Some(rounds as u64), "rounds");
+			for (src, insp) in inspected.iter_mut().enumerate() {
+				let num_items = self.0[src].len();
+				*insp += num_items;
+
+				for item_idx in (0..num_items).rev() {
+					let monkey = &mut self.0[src];
+					let item = monkey.remove(item_idx);
+					// Reducing modulo the product of all divisors keeps
+					// every test result intact, but does not commute with
+					// the division, so it only applies when there is none
+					let item = match (div, modulo) {
+						(1, Some(modulo)) => (monkey.inspect(item) % modulo as u128) as Worry,
+						_ => Worry::try_from(monkey.inspect(item) / div as u128).ok()?,
+					};
+					let dst = monkey.test(item);
+					self.0[dst].throw(item);
+				}
+			}
+			on_round(self, &inspected);
+		}
+
+		Some(inspected)
+	}
+}
+
+impl ToJson for Monkeys {
+	fn to_json(&self) -> Json {
+		self.0.to_json()
+	}
+}
+
+impl FromStr for Monkeys {
+	type Err = ParseError;
+	fn from_str(st: &str) -> Result<Self, Self::Err> {
+		let blocks = common::blocks(st).collect::<Vec<_>>();
+		let mut monkeys = Vec::with_capacity(blocks.len());
+
+		for (idx, &(start, block)) in blocks.iter().enumerate() {
+			let monkey = block.parse::<monkey::Monkey>()
+				.map_err(|e| e.at_line(start))?;
+
+			// Throw targets live on the last two lines of the block
+			for (offset, dst) in (4..).zip(monkey.targets()) {
+				if dst < blocks.len() && dst != idx {
+					continue;
+				}
+				let line = block.split('\n').nth(offset).unwrap_or("");
+				let col = line.rfind(' ').map_or(0, |c| c + 1);
+				let expected = format!(
+					"a monkey other than {} below {}", idx, blocks.len());
+				return Err(ParseError::new(col, &dst.to_string(), expected)
+					.at_line(start + offset));
+			}
+			monkeys.push(monkey);
+		}
+
+		Ok(Self(monkeys))
+	}
+}
+
+// How long a part runs and how much worry drops after each inspection
+#[derive(Debug, Clone, Copy)]
+pub struct Rules {
+	pub rounds: usize,
+	pub div: Worry,
+}
+
+impl ToJson for Rules {
+	fn to_json(&self) -> Json {
+		Json::object([
+			("rounds", self.rounds.to_json()),
+			("div", self.div.to_json()),
+		])
+	}
+}
+
+#[derive(Clone)]
+pub struct KeepAway {
+	pub monkeys: Monkeys,
+	pub part1: Rules,
+	pub part2: Rules,
+}
+
+impl KeepAway {
+	fn monkey_business(&self, rules: Rules) -> Found<usize> {
+		let Some(mut act) = self.monkeys.clone().emulate(rules.rounds, rules.div) else {
+			return Found(None);
+		};
+		act.sort();
+		Found(Some(act.iter()
+			.rev()
+			.take(2)
+			.product()))
+	}
+}
+
+impl ToJson for KeepAway {
+	fn to_json(&self) -> Json {
+		Json::object([
+			("monkeys", self.monkeys.to_json()),
+			("part1", self.part1.to_json()),
+			("part2", self.part2.to_json()),
+		])
+	}
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+	type Input = KeepAway;
+	type Answer1 = Found<usize>;
+	type Answer2 = Found<usize>;
+
+	fn parse(inp: &str) -> Result<Self::Input, ParseError> {
+		Ok(KeepAway {
+			monkeys: inp.parse::<Monkeys>()?,
+			part1: Rules { rounds: 20, div: 3 },
+			part2: Rules { rounds: 10000, div: 1 },
+		})
+	}
+
+	fn part1(game: &Self::Input) -> Found<usize> {
+		game.monkey_business(game.part1)
+	}
+
+	fn part2(game: &Self::Input) -> Found<usize> {
+		game.monkey_business(game.part2)
+	}
+
+	fn configure(game: &mut Self::Input, params: &Params) -> Result<(), Error> {
+		params.expect(&["part1.rounds", "part1.div", "part2.rounds", "part2.div"])?;
+		params.apply("part1.rounds", 0, &mut game.part1.rounds)?;
+		params.apply("part1.div", 1, &mut game.part1.div)?;
+		params.apply("part2.rounds", 0, &mut game.part2.rounds)?;
+		params.apply("part2.div", 1, &mut game.part2.div)
+	}
+}
@@ -1,6 +1,7 @@
 use std::str::FromStr;
 use std::collections::VecDeque;
-use std::num::ParseIntError;
+
+use common::{Json, ParseError, Scanner, ToJson};
 
 use crate::Worry;
 use crate::operator::Operator;
@@ -26,17 +27,24 @@ impl Monkey {
 		self.starting.len()
 	}
 
-	pub fn inspect(&self, item: Worry) -> Worry {
+	pub fn is_empty(&self) -> bool {
+		self.starting.is_empty()
+	}
+
+	// Works in twice the width of a worry level, where a single operation
+	// can't overflow
+	pub fn inspect(&self, item: Worry) -> u128 {
+		let item = item as u128;
 		match self.operator {
-			Operator::Add(v) => item + v,
-			Operator::Mul(v) => item * v,
+			Operator::Add(v) => item + v as u128,
+			Operator::Mul(v) => item * v as u128,
 			Operator::Pow(2) => item * item,
 			_ => unreachable!(),
 		}
 	}
 
 	pub fn test(&self, item: Worry) -> usize {
-		match item % self.div() == 0 {
+		match item.is_multiple_of(self.div()) {
 			true => self.test.if_true,
 			false => self.test.if_false,
 		}
@@ -45,28 +53,72 @@ impl Monkey {
 	pub fn div(&self) -> Worry {
 		self.test.div
 	}
+
+	// Items in the order they will be inspected
+	pub fn items(&self) -> impl Iterator<Item=Worry> + '_ {
+		self.starting.iter().rev().copied()
+	}
+
+	pub fn operator(&self) -> Operator {
+		self.operator
+	}
+
+	pub fn targets(&self) -> [usize; 2] {
+		[self.test.if_true, self.test.if_false]
+	}
+}
+
+impl ToJson for Monkey {
+	fn to_json(&self) -> Json {
+		Json::object([
+			("items", self.items().collect::<Vec<_>>().to_json()),
+			("operator", self.operator.to_json()),
+			("test", self.test.to_json()),
+		])
+	}
+}
+
+// Items are stored in reverse so that the next one to inspect is at the back
+fn parse_items(line: &str) -> Result<VecDeque<Worry>, ParseError> {
+	let mut sc = Scanner::new(line);
+	sc.skip_whitespace();
+	sc.tag("Starting items:")?;
+	let mut items = Vec::new();
+	if !sc.is_empty() {
+		sc.tag(" ")?;
+		loop {
+			items.push(sc.number::<Worry>()?);
+			if !sc.eat(',') {
+				break;
+			}
+			sc.tag(" ")?;
+		}
+	}
+	sc.end()?;
+	items.reverse();
+	Ok(VecDeque::from(items))
 }
 
 impl FromStr for Monkey {
-	type Err = ParseIntError;
+	type Err = ParseError;
 	fn from_str(s: &str) -> Result<Self, Self::Err> {
 		let mut lines = s.split('\n');
-		assert!(lines.next().unwrap().starts_with("Monkey "));
-
-		let starting = lines.next().unwrap()
-			.split(" items: ")
-			.nth(1)
-			.unwrap()
-			.split(", ")
-			.map(|n| n.parse::<Worry>())
-			.collect::<Result<Vec<_>, _>>()
-			.map(|mut v| {
-				v.reverse();
-				VecDeque::from_iter(v)
-			})?;
-
-		let operator = lines.next().unwrap().parse::<Operator>()?;
-		let test = lines.collect::<Vec<_>>().join("\n").parse::<Test>()?;
+
+		let mut sc = Scanner::new(lines.next().unwrap_or(""));
+		sc.tag("Monkey ")?;
+		sc.number::<usize>()?;
+		sc.tag(":")?;
+		sc.end()?;
+
+		let starting = parse_items(lines.next().unwrap_or(""))
+			.map_err(|e| e.at_line(1))?;
+
+		let operator = lines.next().unwrap_or("")
+			.parse::<Operator>()
+			.map_err(|e| e.at_line(2))?;
+		let test = lines.collect::<Vec<_>>().join("\n")
+			.parse::<Test>()
+			.map_err(|e| e.at_line(3))?;
 		Ok(Self { starting, operator, test })
 	}
-}
\ No newline at end of file
+}
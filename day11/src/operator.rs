@@ -1,5 +1,6 @@
 use std::str;
-use std::num::ParseIntError;
+
+use common::{Json, ParseError, Scanner, ToJson};
 
 use crate::Worry;
 
@@ -10,23 +11,39 @@ pub enum Operator {
 	Pow(usize),
 }
 
+impl ToJson for Operator {
+	fn to_json(&self) -> Json {
+		let (op, value) = match *self {
+			Self::Add(v) => ("add", v),
+			Self::Mul(v) => ("mul", v),
+			Self::Pow(n) => ("pow", n as Worry),
+		};
+		Json::object([("op", op.to_json()), ("value", value.to_json())])
+	}
+}
+
 impl str::FromStr for Operator {
-	type Err = ParseIntError;
+	type Err = ParseError;
 	fn from_str(s: &str) -> Result<Self, Self::Err> {
-		let fields = s.trim();
-		assert!(fields.starts_with("Operation: new = old"));
+		let mut sc = Scanner::new(s);
+		sc.skip_whitespace();
+		sc.tag("Operation: new = old ")?;
 
-		let mut fields = fields.split(' ').skip(4);
-		match fields.next().unwrap() {
-			"*" => match fields.next().unwrap() {
-				"old" => Ok(Self::Pow(2)),
-				imm => Ok(Self::Mul(imm.parse::<Worry>()?))
-			},
-			"+" => match fields.next().unwrap() {
-				"old" => Ok(Self::Mul(2)),
-				imm => Ok(Self::Add(imm.parse::<Worry>()?))
-			},
-			_ => todo!()
+		let col = sc.col();
+		let op = sc.word()?;
+		sc.tag(" ")?;
+		let old = sc.rest() == "old";
+		let op = match (op, old) {
+			("*", true) => Self::Pow(2),
+			("*", false) => Self::Mul(sc.number::<Worry>()?),
+			("+", true) => Self::Mul(2),
+			("+", false) => Self::Add(sc.number::<Worry>()?),
+			(op, _) => return Err(ParseError::new(col, op, "`*` or `+`")),
+		};
+		if old {
+			sc.tag("old")?;
 		}
+		sc.end()?;
+		Ok(op)
 	}
-}
\ No newline at end of file
+}
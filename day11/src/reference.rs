@@ -0,0 +1,109 @@
+// A straightforward emulation using exact worry levels, with no modular
+// arithmetic, to check `Monkeys::emulate()` against.
+
+use std::collections::VecDeque;
+
+use crate::Monkeys;
+use crate::operator::Operator;
+
+// An arbitrarily large unsigned integer, as little-endian base 2^32 digits
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct BigUint(Vec<u32>);
+
+impl BigUint {
+	fn new(n: u64) -> Self {
+		let mut big = Self(vec![n as u32, (n >> 32) as u32]);
+		big.trim();
+		big
+	}
+
+	fn trim(&mut self) {
+		while self.0.last() == Some(&0) {
+			self.0.pop();
+		}
+	}
+
+	fn add_small(&self, n: u64) -> Self {
+		let mut out = Vec::with_capacity(self.0.len() + 2);
+		let mut carry = n as u128;
+		for d in self.0.iter() {
+			carry += *d as u128;
+			out.push(carry as u32);
+			carry >>= 32;
+		}
+		while carry > 0 {
+			out.push(carry as u32);
+			carry >>= 32;
+		}
+		let mut big = Self(out);
+		big.trim();
+		big
+	}
+
+	fn mul(&self, other: &Self) -> Self {
+		let mut out = vec![0u32; self.0.len() + other.0.len()];
+		for (i, a) in self.0.iter().enumerate() {
+			let mut carry = 0u64;
+			for (j, b) in other.0.iter().enumerate() {
+				let cur = out[i + j] as u64 + *a as u64 * *b as u64 + carry;
+				out[i + j] = cur as u32;
+				carry = cur >> 32;
+			}
+			let mut k = i + other.0.len();
+			while carry > 0 {
+				let cur = out[k] as u64 + carry;
+				out[k] = cur as u32;
+				carry = cur >> 32;
+				k += 1;
+			}
+		}
+		let mut big = Self(out);
+		big.trim();
+		big
+	}
+
+	// Divides by a small number, returning the quotient and remainder
+	fn div_rem(&self, n: u32) -> (Self, u32) {
+		let mut out = vec![0u32; self.0.len()];
+		let mut rem = 0u64;
+		for (i, d) in self.0.iter().enumerate().rev() {
+			let cur = (rem << 32) | *d as u64;
+			out[i] = (cur / n as u64) as u32;
+			rem = cur % n as u64;
+		}
+		let mut big = Self(out);
+		big.trim();
+		(big, rem as u32)
+	}
+}
+
+// The number of items each monkey inspects over `rounds` rounds, dividing
+// worry levels by `div` after each inspection
+pub fn emulate(monkeys: &Monkeys, rounds: usize, div: u32) -> Vec<usize> {
+	let mut items: Vec<VecDeque<BigUint>> = monkeys.iter()
+		.map(|m| m.items().map(BigUint::new).collect())
+		.collect();
+	let mut inspected = vec![0; items.len()];
+
+	for _ in 0..rounds {
+		for (src, monkey) in monkeys.iter().enumerate() {
+			while let Some(item) = items[src].pop_front() {
+				inspected[src] += 1;
+				let item = match monkey.operator() {
+					Operator::Add(v) => item.add_small(v),
+					Operator::Mul(v) => item.mul(&BigUint::new(v)),
+					Operator::Pow(_) => item.mul(&item),
+				};
+				let (item, _) = item.div_rem(div);
+				let [if_true, if_false] = monkey.targets();
+				let dst = match item.div_rem(monkey.div() as u32).1 {
+					0 => if_true,
+					_ => if_false,
+				};
+				items[dst].push_back(item);
+			}
+		}
+	}
+
+	inspected
+}
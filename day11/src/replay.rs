@@ -0,0 +1,63 @@
+use visual::{Simulation, Visualize};
+
+use crate::{Day11, KeepAway, Monkeys, Worry};
+
+// What a monkey holds and has inspected so far
+struct MonkeyState {
+	items: Vec<Worry>,
+	inspected: usize,
+}
+
+// The monkeys after each round
+struct Replay {
+	rounds: Vec<Vec<MonkeyState>>,
+}
+
+fn snapshot(monkeys: &Monkeys, inspected: &[usize]) -> Vec<MonkeyState> {
+	monkeys.iter()
+		.zip(inspected)
+		.map(|(m, &inspected)| MonkeyState { items: m.items().collect(), inspected })
+		.collect()
+}
+
+impl Replay {
+	fn new(game: &KeepAway, part: u8) -> Self {
+		let monkeys = &game.monkeys;
+		let rules = if part == 1 { game.part1 } else { game.part2 };
+		let mut rounds = vec![snapshot(monkeys, &vec![0; monkeys.len()])];
+		monkeys.clone().emulate_with(rules.rounds, rules.div, |m, inspected|
+			rounds.push(snapshot(m, inspected)));
+		Self { rounds }
+	}
+}
+
+impl Simulation for Replay {
+	fn steps(&self) -> usize {
+		self.rounds.len() - 1
+	}
+
+	fn render(&self, step: usize) -> String {
+		let monkeys = &self.rounds[step];
+		let mut out = format!("After round {}\n", step);
+		for (i, m) in monkeys.iter().enumerate() {
+			let items = m.items.iter()
+				.map(|w| w.to_string())
+				.collect::<Vec<_>>()
+				.join(", ");
+			out.push_str(&format!("\nMonkey {} ({:>6} inspected): {}",
+				i, m.inspected, items));
+		}
+
+		let mut active: Vec<_> = monkeys.iter().map(|m| m.inspected).collect();
+		active.sort();
+		let business: usize = active.iter().rev().take(2).product();
+		out.push_str(&format!("\n\nmonkey business {}", business));
+		out
+	}
+}
+
+impl Visualize for Day11 {
+	fn simulation(game: &Self::Input, part: u8) -> Box<dyn Simulation> {
+		Box::new(Replay::new(game, part))
+	}
+}
@@ -1,5 +1,6 @@
 use std::str;
-use std::num::ParseIntError;
+
+use common::{Json, ParseError, Scanner, ToJson};
 
 use crate::Worry;
 
@@ -10,29 +11,45 @@ pub struct Test {
 	pub if_false: usize,
 }
 
+impl ToJson for Test {
+	fn to_json(&self) -> Json {
+		Json::object([
+			("div", self.div.to_json()),
+			("if_true", self.if_true.to_json()),
+			("if_false", self.if_false.to_json()),
+		])
+	}
+}
+
+// Parses the number at the end of a line starting with `prefix`, along
+// with the column it was found at
+fn parse_line(line: Option<&str>, prefix: &str)
+	-> Result<(usize, usize), ParseError>
+{
+	let mut sc = Scanner::new(line.unwrap_or(""));
+	sc.skip_whitespace();
+	sc.tag(prefix)?;
+	let col = sc.col();
+	let num = sc.number::<usize>()?;
+	sc.end()?;
+	Ok((num, col))
+}
+
 impl str::FromStr for Test {
-	type Err = ParseIntError;
+	type Err = ParseError;
 	fn from_str(s: &str) -> Result<Self, Self::Err> {
 		let mut lines = s.split('\n');
 
-		let div = lines.next().unwrap().trim();
-		assert!(div.starts_with("Test: divisible by"));
-		let div = div.split(' ')
-			.nth(3).unwrap()
-			.parse::<Worry>()?;
-
-		let if_true = lines.next().unwrap().trim();
-		assert!(if_true.starts_with("If true: throw to monkey"));
-		let if_true = if_true.split(' ')
-			.nth(5).unwrap()
-			.parse::<usize>()?;
-
-		let if_false = lines.next().unwrap().trim();
-		assert!(if_false.starts_with("If false: throw to monkey"));
-		let if_false = if_false.split(' ')
-			.nth(5).unwrap()
-			.parse::<usize>()?;
-
-		Ok(Self { div, if_true, if_false })
+		let (div, col) = parse_line(lines.next(), "Test: divisible by ")?;
+		if div == 0 {
+			return Err(ParseError::new(col, "0", "a non-zero divisor"));
+		}
+
+		let (if_true, _) = parse_line(lines.next(), "If true: throw to monkey ")
+			.map_err(|e| e.at_line(1))?;
+		let (if_false, _) = parse_line(lines.next(), "If false: throw to monkey ")
+			.map_err(|e| e.at_line(2))?;
+
+		Ok(Self { div: div as Worry, if_true, if_false })
 	}
 }
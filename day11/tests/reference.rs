@@ -0,0 +1,15 @@
+use common::{Generate, Rng};
+use day11::{Day11, Monkeys, reference};
+
+#[test]
+fn emulate_matches_exact_arithmetic() {
+	for seed in 0..20 {
+		let inp = Day11::generate(&mut Rng::new(seed), 1);
+		let monkeys = inp.parse::<Monkeys>().unwrap();
+
+		assert_eq!(monkeys.clone().emulate(20, 3),
+			Some(reference::emulate(&monkeys, 20, 3)), "seed {}", seed);
+		assert_eq!(monkeys.clone().emulate(12, 1),
+			Some(reference::emulate(&monkeys, 12, 1)), "seed {}", seed);
+	}
+}
@@ -0,0 +1,57 @@
+use common::{Generate, Rng};
+use common::rng::scale_side;
+use grid::Grid;
+
+use crate::Day12;
+
+// Carves a random spanning tree rooted at the destination, then makes
+// cells lower the further along the tree they are from it. Walking along the
+// tree towards the destination never climbs more than one step, so every
+// cell can reach it. The map must be large enough for some cell to be 25
+// steps away from the destination.
+pub fn generate_sized(rng: &mut Rng, height: usize, width: usize) -> String {
+	let (dst, dist) = loop {
+		let dst = (rng.below(height), rng.below(width));
+
+		// Randomized Prim's: grow the tree from a random frontier edge
+		let mut dist: Grid<Option<usize>> = Grid::new(width, height, None);
+		let mut frontier = vec![(dst, dst)];
+		while !frontier.is_empty() {
+			let idx = rng.below(frontier.len());
+			let (from, to) = frontier.swap_remove(idx);
+			if dist[to].is_some() {
+				continue;
+			}
+			dist[to] = Some(dist[from].map_or(0, |d| d + 1));
+			frontier.extend(dist.neighbours4(to)
+				.filter(|p| dist[*p].is_none())
+				.map(|p| (to, p)));
+		}
+
+		let dist = dist.map(|d| d.unwrap());
+		if dist.iter().any(|(_, d)| *d >= 25) {
+			break (dst, dist);
+		}
+	};
+
+	let max = *dist.iter().map(|(_, d)| d).max().unwrap();
+	let step = max / 25;
+	let start = dist.position(|d| *d == max).unwrap();
+
+	let mut rows: Vec<String> = dist.rows()
+		.map(|row| row.iter()
+			.map(|d| (b'z' - (d / step).min(25) as u8) as char)
+			.collect())
+		.collect();
+	for (pos, mark) in [(start, "S"), (dst, "E")] {
+		rows[pos.0].replace_range(pos.1..=pos.1, mark);
+	}
+	rows.into_iter().map(|row| row + "\n").collect()
+}
+
+impl Generate for Day12 {
+	fn generate(rng: &mut Rng, scale: usize) -> String {
+		let height = scale_side(41, scale);
+		generate_sized(rng, height, height * 4)
+	}
+}
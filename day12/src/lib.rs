@@ -0,0 +1,203 @@
+use std::borrow::Borrow;
+use std::collections::{HashSet, HashMap};
+use std::str::{self, FromStr};
+
+use common::{Json, ParseError, Solution, ToJson};
+use common::progress;
+use geometry::Point;
+use grid::Grid;
+
+mod generate;
+pub mod reference;
+
+pub use generate::generate_sized;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Cell(u64);
+
+impl Cell {
+	fn can_climb<C: Borrow<Self>>(&self, other: C) -> bool {
+		self.0 + 1 >= other.borrow().0
+	}
+}
+
+impl From<char> for Cell {
+	fn from(c: char) -> Self {
+		match c {
+			'S' => Self(0),
+			'E' => Self::from('z'),
+			_   => Self(((c as u8) - b'a') as u64)
+		}
+	}
+}
+
+impl ToJson for Cell {
+	fn to_json(&self) -> Json {
+		self.0.to_json()
+	}
+}
+
+pub type Coords = Point<usize>;
+
+#[derive(Debug, Clone)]
+pub struct MapDistance(HashMap<Coords, usize>);
+
+impl MapDistance {
+	fn new() -> Self { Self(HashMap::new()) }
+
+	pub fn get<C: Borrow<Coords>>(&self, p: C) -> Option<usize> {
+		self.0.get(p.borrow()).copied()
+	}
+
+	fn set_path_len(&mut self, p: Coords, path_len: usize) {
+		if self.0.get(&p).map(|old| *old > path_len).unwrap_or(true) {
+			self.0.insert(p, path_len);
+		}
+	}
+}
+
+#[derive(Clone, Debug)]
+pub struct Map {
+	start: Coords,
+	dst: Coords,
+	map: Grid<Cell>,
+	paths: MapDistance,
+}
+
+impl Map {
+	fn get<B: Borrow<Coords>>(&self, crd: B) -> Cell {
+		self.map[*crd.borrow()]
+	}
+
+	fn next_steps<C: Borrow<Coords> + Copy>(&self, cur: C) -> Vec<Coords> {
+		cur.borrow().neighbours4(self.map.bounds())
+			.filter(|p| self.get(p).can_climb(self.get(cur)))
+			.collect()
+	}
+
+	// Computes the shortest path to the destination from every
+	// reachable cell. The search is depth-first, but keeps its own stack
+	// of the steps left to try from each cell of the path, since paths get
+	// as long as the map is large.
+	fn walk(&mut self) {
+		let mut path = Vec::new();
+		let mut seen = HashSet::new();
+		let mut todo: Vec<Vec<Coords>> = Vec::new();
+		let mut visited = 0;
+		let mut next = Some(self.dst);
+		loop {
+			if let Some(p) = next.take() {
+				path.push(p);
+				seen.insert(p);
+				todo.push(self.visit(&path, &seen, &mut visited));
+			}
+			let Some(steps) = todo.last_mut() else {
+				break;
+			};
+			match steps.pop() {
+				// If we do not know a path for the step, or the known path
+				// is longer than our current one, explore it
+				Some(p) => if self.paths.get(p).map(|v| v > path.len() + 1)
+					.unwrap_or(true)
+				{
+					next = Some(p);
+				},
+				None => {
+					todo.pop();
+					seen.remove(&path.pop().unwrap());
+				}
+			}
+		}
+	}
+
+	// Records the path ending the current one, and returns the possible
+	// next steps from its last cell, last to be tried first. `visited`
+	// counts the calls so far, for progress reports.
+	fn visit(&mut self, path: &[Coords], seen: &HashSet<Coords>, visited: &mut u64)
+		-> Vec<Coords>
+	{
+		*visited += 1;
+		progress::report(*visited, None, "nodes visited");
+		let cur = path.last().unwrap();
+		self.paths.set_path_len(*cur, path.len());
+		if *cur == self.start {
+			return Vec::new();
+		}
+
+		let mut possib = self.next_steps(cur);
+		possib.retain(|p| !seen.contains(p));
+		if possib.contains(&self.start) {
+			possib.retain(|p| *p == self.start);
+		}
+		possib.reverse();
+		possib
+	}
+}
+
+// Finds the single cell marked with `mark`
+fn find_mark(chars: &Grid<char>, mark: char, what: &str)
+	-> Result<Coords, ParseError>
+{
+	let mut found = chars.points().filter(|p| chars[*p] == mark);
+	match (found.next(), found.next()) {
+		(Some(p), None) => Ok(p),
+		(_, Some(p)) => Err(ParseError::new(p.x, &mark.to_string(),
+			format!("a single {} `{}`", what, mark)).at_line(p.y)),
+		(None, None) => Err(ParseError::new(0, "",
+			format!("a {} `{}`", what, mark)).at_line(chars.height())),
+	}
+}
+
+impl FromStr for Map {
+	type Err = ParseError;
+	fn from_str(st: &str) -> Result<Self, Self::Err> {
+		let chars = Grid::parse(st, |c| match c {
+			'a'..='z' | 'S' | 'E' => Ok(c),
+			_ => Err("a height letter, `S` or `E`"),
+		})?;
+		let start = find_mark(&chars, 'S', "start")?;
+		let dst = find_mark(&chars, 'E', "destination")?;
+		let map = chars.map(|c| Cell::from(*c));
+		let paths = MapDistance::new();
+		Ok(Self { start, dst, map, paths })
+	}
+}
+
+// The heights, from 0 for `a`, and the marked cells; distances are only
+// known once solving
+impl ToJson for Map {
+	fn to_json(&self) -> Json {
+		Json::object([
+			("start", self.start.to_json()),
+			("dst", self.dst.to_json()),
+			("heights", self.map.to_json()),
+		])
+	}
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+	type Input = Map;
+	type Answer1 = usize;
+	type Answer2 = usize;
+
+	fn parse(inp: &str) -> Result<Self::Input, ParseError> {
+		inp.parse::<Map>()
+	}
+
+	fn part1(map: &Self::Input) -> usize {
+		let mut map = map.clone();
+		map.walk();
+		map.paths.get(map.start).unwrap() - 1
+	}
+
+	fn part2(map: &Self::Input) -> usize {
+		let mut map = map.clone();
+		map.walk();
+		map.map.points()
+			.filter(|c| map.get(c) == Cell(0))
+			.filter_map(|c| map.paths.get(c))
+			.min().unwrap() - 1
+	}
+}
@@ -0,0 +1,41 @@
+// A plain breadth-first search over the map, to check the pruned
+// `Map::walk()` against.
+
+use std::collections::VecDeque;
+
+use grid::Grid;
+
+use crate::{Cell, Coords, Map};
+
+// Fewest steps from any of the starting cells to the destination, if it
+// can be reached at all
+fn bfs(map: &Map, starts: impl Iterator<Item=Coords>) -> Option<usize> {
+	let mut dist: Grid<Option<usize>> = map.map.map(|_| None);
+	let mut queue = VecDeque::new();
+	for p in starts {
+		dist[p] = Some(0);
+		queue.push_back(p);
+	}
+
+	while let Some(cur) = queue.pop_front() {
+		let d = dist[cur].unwrap();
+		if cur == map.dst {
+			return Some(d);
+		}
+		for next in cur.neighbours4(map.map.bounds()) {
+			if dist[next].is_none() && map.get(cur).can_climb(map.get(next)) {
+				dist[next] = Some(d + 1);
+				queue.push_back(next);
+			}
+		}
+	}
+	None
+}
+
+pub fn part1(map: &Map) -> Option<usize> {
+	bfs(map, [map.start].into_iter())
+}
+
+pub fn part2(map: &Map) -> Option<usize> {
+	bfs(map, map.map.points().filter(|p| map.get(p) == Cell(0)))
+}
@@ -0,0 +1,15 @@
+use common::{Rng, Solution};
+use day12::{Day12, reference};
+
+#[test]
+fn walk_matches_bfs() {
+	for seed in 0..20 {
+		let inp = day12::generate_sized(&mut Rng::new(seed), 8, 32);
+		let map = Day12::parse(&inp).unwrap();
+
+		assert_eq!(Some(Day12::part1(&map)), reference::part1(&map),
+			"seed {}", seed);
+		assert_eq!(Some(Day12::part2(&map)), reference::part2(&map),
+			"seed {}", seed);
+	}
+}
@@ -0,0 +1,34 @@
+use common::{Generate, Rng};
+
+use crate::{Day13, Packet};
+
+fn packet(rng: &mut Rng, depth: usize) -> String {
+	let items = (0..rng.range(0..=4))
+		.map(|_| match depth < 4 && rng.chance(0.3) {
+			true => packet(rng, depth + 1),
+			false => rng.range(0..=10).to_string(),
+		})
+		.collect::<Vec<_>>();
+	format!("[{}]", items.join(","))
+}
+
+// A packet that does not compare equal to either divider packet, which
+// would make them ambiguous when sorting
+fn non_divider(rng: &mut Rng) -> String {
+	let dividers = ["[[2]]", "[[6]]"].map(|d| d.parse::<Packet>().unwrap());
+	loop {
+		let p = packet(rng, 0);
+		if !dividers.contains(&p.parse::<Packet>().unwrap()) {
+			return p;
+		}
+	}
+}
+
+impl Generate for Day13 {
+	fn generate(rng: &mut Rng, scale: usize) -> String {
+		(0..150 * scale)
+			.map(|_| format!("{}\n{}\n", non_divider(rng), non_divider(rng)))
+			.collect::<Vec<_>>()
+			.join("\n")
+	}
+}
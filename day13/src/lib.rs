@@ -0,0 +1,169 @@
+use std::cmp::Ordering;
+use std::iter;
+use std::str::FromStr;
+
+use common::{Json, ParseError, Scanner, Solution, ToJson};
+
+mod generate;
+
+// Packets are dense, so only report the single offending character
+fn unexpected(sc: &Scanner, expected: &str) -> ParseError {
+	let found = sc.peek().map(String::from).unwrap_or_default();
+	ParseError::new(sc.col(), &found, expected)
+}
+
+// Parsing, comparing and dropping packets all recurse into nested lists, so
+// their depth is bounded to keep within the stack
+const MAX_DEPTH: usize = 100;
+
+#[derive(Debug, Clone, Eq)]
+pub enum Packet {
+	Imm(u64),
+	List(Vec<Packet>),
+}
+
+impl Packet {
+	fn parse_node(sc: &mut Scanner, depth: usize) -> Result<Packet, ParseError> {
+		if sc.peek().is_some_and(|c| c.is_ascii_digit()) {
+			return sc.number::<u64>().map(Self::Imm);
+		}
+		if depth == MAX_DEPTH && sc.peek() == Some('[') {
+			return Err(unexpected(sc,
+				&format!("at most {} nested lists", MAX_DEPTH)));
+		}
+		if !sc.eat('[') {
+			return Err(unexpected(sc, "a number or `[`"));
+		}
+
+		let mut out = Vec::new();
+		if sc.eat(']') {
+			return Ok(Self::List(out));
+		}
+		loop {
+			out.push(Self::parse_node(sc, depth + 1)?);
+			if sc.eat(']') {
+				return Ok(Self::List(out));
+			}
+			if !sc.eat(',') {
+				return Err(unexpected(sc, "`,` or `]`"));
+			}
+		}
+	}
+
+	fn to_list(&self) -> Self {
+		match self {
+			Self::Imm(_) => Packet::List(vec![self.clone()]),
+			_ => unreachable!(),
+		}
+	}
+}
+
+impl FromStr for Packet {
+	type Err = ParseError;
+	fn from_str(st: &str) -> Result<Self, Self::Err> {
+		let mut sc = Scanner::new(st);
+		let packet = Self::parse_node(&mut sc, 0)?;
+		sc.end()?;
+		Ok(packet)
+	}
+}
+
+impl PartialOrd for Packet {
+	fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+		match (self, other) {
+			(Packet::Imm(a), Packet::Imm(b)) => Some(a.cmp(b)),
+			(Packet::List(a), Packet::List(b)) =>
+				a.iter().zip(b)
+					.map(|(f, s)| f.partial_cmp(s).unwrap())
+					.find(|r| *r != Ordering::Equal)
+					.or_else(|| Some(a.len().cmp(&b.len()))),
+			(Packet::List(_), Packet::Imm(_)) =>
+				self.partial_cmp(&other.to_list()),
+			(Packet::Imm(_), Packet::List(_)) =>
+				self.to_list().partial_cmp(other),
+		}
+	}
+}
+
+// Packets are written in JSON to begin with
+impl ToJson for Packet {
+	fn to_json(&self) -> Json {
+		match self {
+			Self::Imm(n) => n.to_json(),
+			Self::List(l) => l.to_json(),
+		}
+	}
+}
+
+impl PartialEq for Packet {
+	fn eq(&self, other: &Self) -> bool {
+		self.partial_cmp(other) == Some(Ordering::Equal)
+	}
+}
+
+pub struct PacketPair {
+	first: Packet,
+	second: Packet,
+}
+
+impl ToJson for PacketPair {
+	fn to_json(&self) -> Json {
+		Json::Array(vec![self.first.to_json(), self.second.to_json()])
+	}
+}
+
+impl FromStr for PacketPair {
+	type Err = ParseError;
+	fn from_str(st: &str) -> Result<Self, Self::Err> {
+		let mut parts = st.lines();
+		let first = parts.next().unwrap_or("").parse::<Packet>()?;
+		let second = parts.next()
+			.ok_or_else(|| ParseError::new(0, "", "a second packet"))
+			.and_then(Packet::from_str)
+			.map_err(|e| e.at_line(1))?;
+		if let Some(extra) = parts.next() {
+			return Err(ParseError::new(0, extra, "a blank line").at_line(2));
+		}
+		Ok(Self { first, second })
+	}
+}
+
+pub struct Day13;
+
+impl Solution for Day13 {
+	type Input = Vec<PacketPair>;
+	type Answer1 = usize;
+	type Answer2 = usize;
+
+	fn parse(inp: &str) -> Result<Self::Input, ParseError> {
+		common::blocks(inp)
+			.map(|(line, block)| block.parse::<PacketPair>()
+				.map_err(|e| e.at_line(line)))
+			.collect::<Result<Vec<_>, _>>()
+	}
+
+	fn part1(pairs: &Self::Input) -> usize {
+		pairs.iter()
+			.enumerate()
+			.filter(|(_, p)| p.first < p.second)
+			.map(|(i, _)| i + 1)
+			.sum()
+	}
+
+	fn part2(pairs: &Self::Input) -> usize {
+		let p1 = "[[2]]".parse::<Packet>().unwrap();
+		let p2 = "[[6]]".parse::<Packet>().unwrap();
+		let mut packets: Vec<_> = pairs.iter()
+			.flat_map(|p| [p.first.clone(), p.second.clone()])
+			.chain(iter::once(p1.clone()))
+			.chain(iter::once(p2.clone()))
+			.collect();
+		packets.sort_by(|a, b| a.partial_cmp(b).unwrap());
+
+		packets.iter()
+			.enumerate()
+			.filter(|(_, p)| **p == p1 || **p == p2)
+			.map(|(i, _)| i + 1)
+			.product()
+	}
+}
@@ -0,0 +1,16 @@
+use common::Solution;
+use day13::Day13;
+
+const EXAMPLE: &str = include_str!("../example.txt");
+
+#[test]
+fn part1() {
+	let input = Day13::parse(EXAMPLE).unwrap();
+	assert_eq!(Day13::part1(&input), 13);
+}
+
+#[test]
+fn part2() {
+	let input = Day13::parse(EXAMPLE).unwrap();
+	assert_eq!(Day13::part2(&input), 140);
+}
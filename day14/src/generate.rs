@@ -0,0 +1,35 @@
+use common::{Generate, Rng};
+use common::rng::scale_side;
+
+use crate::Day14;
+
+impl Generate for Day14 {
+	// Paths are kept around the sand source, alternating between
+	// horizontal and vertical segments. Like in real inputs, the same path
+	// is often listed several times.
+	fn generate(rng: &mut Rng, scale: usize) -> String {
+		let span = scale_side(40, scale) as i64;
+		let depth = scale_side(160, scale) as i64;
+		let xs = (500 - span).max(1)..=500 + span;
+		let ys = 13..=depth;
+
+		(0..25 * scale)
+			.map(|_| {
+				let mut x = rng.range(xs.clone());
+				let mut y = rng.range(ys.clone());
+				let mut horizontal = rng.chance(0.5);
+				let mut points = vec![format!("{},{}", x, y)];
+				for _ in 0..rng.range(1..=5) {
+					let len = rng.range(-10..=10);
+					match horizontal {
+						true => x = (x + len).clamp(*xs.start(), *xs.end()),
+						false => y = (y + len).clamp(*ys.start(), *ys.end()),
+					}
+					horizontal = !horizontal;
+					points.push(format!("{},{}", x, y));
+				}
+				(points.join(" -> ") + "\n").repeat(rng.range(1..=8) as usize)
+			})
+			.collect()
+	}
+}
@@ -0,0 +1,214 @@
+use std::cmp;
+use std::fmt::Write;
+use std::ops::RangeInclusive;
+
+use common::{ParseError, Scanner, Solution};
+use geometry::{Direction8, Point};
+use grid::Grid;
+
+mod generate;
+mod replay;
+
+fn min_max<T: Ord>(a: T, b: T) -> (T, T) {
+	match a < b {
+		true  => (a, b),
+		false => (b, a),
+	}
+}
+
+pub type Coords = Point<usize>;
+
+// Where sand pours in from
+const SAND: Coords = Coords::new(500, 0);
+
+fn scan_coords(sc: &mut Scanner) -> Result<Coords, ParseError> {
+	let x = sc.number::<usize>()?;
+	sc.tag(",")?;
+	let y = sc.number::<usize>()?;
+	Ok(Coords::new(x, y))
+}
+
+pub type RockPath = Vec<Coords>;
+
+// Every segment of a path must be either horizontal or vertical
+fn parse_path(line: &str) -> Result<RockPath, ParseError> {
+	let mut sc = Scanner::new(line);
+	let mut path = vec![scan_coords(&mut sc)?];
+	while !sc.is_empty() {
+		sc.tag(" -> ")?;
+		let col = sc.col();
+		let crd = scan_coords(&mut sc)?;
+		let prev = path[path.len() - 1];
+		if crd.x != prev.x && crd.y != prev.y {
+			let expected = format!("a point in line with {}", prev);
+			return Err(ParseError::new(col, &crd.to_string(), expected));
+		}
+		path.push(crd);
+	}
+	Ok(path)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Cell {
+	Rock,
+	Air,
+	Sand,
+}
+
+#[derive(Debug, Clone)]
+pub struct Map {
+	map: Grid<Cell>,
+	sand: Coords,
+}
+
+impl Map {
+	fn new(width: usize, height: usize, sand: Coords) -> Self {
+		Self {
+			sand,
+			map: Grid::new(width, height, Cell::Air),
+		}
+	}
+
+	fn set_rock_paths(&mut self, paths: &[RockPath]) {
+		for path in paths {
+			for i in 1..path.len() {
+				let a = &path[i - 1];
+				let b = &path[i];
+				let (min_x, max_x) = min_max(a.x, b.x);
+				let (min_y, max_y) = min_max(a.y, b.y);
+				match (a.x == b.x, a.y == b.y) {
+					(true, _) => (min_y..=max_y)
+						.for_each(|y| self.map[Coords::new(a.x, y)] = Cell::Rock),
+					(false, true) => (min_x..=max_x)
+						.for_each(|x| self.map[Coords::new(x, a.y)] = Cell::Rock),
+					(..) => unreachable!(),
+				}
+			}
+		}
+	}
+
+	// Draws the columns in `xs`, with the sand source as `+` until sand
+	// covers it
+	fn render(&self, xs: RangeInclusive<usize>) -> String {
+		let mut out = String::new();
+		for (y, row) in self.map.rows().enumerate() {
+			for x in xs.clone() {
+				out.push(match row[x] {
+					Cell::Air if Coords::new(x, y) == self.sand => '+',
+					Cell::Air  => '.',
+					Cell::Sand => 'o',
+					Cell::Rock => '#',
+				});
+			}
+			writeln!(out).unwrap();
+		}
+		out.pop();
+		out
+	}
+
+	// Where a unit of sand at `sand` moves next: straight down, else down
+	// and to the left, else down and to the right, if any is free
+	fn fall(&self, sand: Coords) -> Option<Coords> {
+		[Direction8::Down, Direction8::DownLeft, Direction8::DownRight]
+			.into_iter()
+			.filter_map(|dir| sand.step_within(dir, self.map.bounds()))
+			.find(|p| self.map[*p] == Cell::Air)
+	}
+
+	// Calls `on_rest` with each unit of sand as it comes to rest
+	fn part1(&mut self, mut on_rest: impl FnMut(Coords)) -> usize {
+		let mut num = 0;
+		let mut sand = self.sand;
+
+		while sand.y < self.map.height() - 1 {
+			match self.fall(sand) {
+				Some(next) => sand = next,
+				None => {
+					self.map[sand] = Cell::Sand;
+					on_rest(sand);
+					num += 1;
+					// Rocks around the source can hold all the sand
+					if sand == self.sand {
+						break;
+					}
+					sand = self.sand;
+				}
+			}
+		}
+		num
+	}
+
+	fn part2(&mut self, mut on_rest: impl FnMut(Coords)) -> usize {
+		let mut num = 0;
+		let mut sand = self.sand;
+
+		loop {
+			match self.fall(sand) {
+				Some(next) => sand = next,
+				None => {
+					self.map[sand] = Cell::Sand;
+					on_rest(sand);
+					num += 1;
+					if sand == self.sand {
+						break;
+					}
+					sand = self.sand;
+				}
+			}
+		}
+
+		num
+	}
+}
+
+// The cave holds every rock and the sand source, and leaves room to the
+// right of the source for a pile on the part 2 floor, which spreads as far
+// sideways as it is high
+fn get_map_size(paths: &[RockPath]) -> (usize, usize) {
+	let mut width: usize = 0;
+	let mut height: usize = 0;
+	for path in paths {
+		for c in path {
+			if c.x > width { width = c.x; }
+			if c.y > height { height = c.y; }
+		}
+	}
+	let height = height + 3;
+	(cmp::max(width + 1, SAND.x + height), height)
+}
+
+// Builds the cave for the given paths, with the floor two rows below the
+// lowest rock for part 2
+fn build_map(paths: &[RockPath], floor: bool) -> Map {
+	let (width, height) = get_map_size(paths);
+	let mut map = Map::new(width, height, SAND);
+	map.set_rock_paths(paths);
+	if floor {
+		let floor = vec![
+			Coords::new(0, height - 1),
+			Coords::new(width - 1, height - 1),
+		];
+		map.set_rock_paths(&[floor]);
+	}
+	map
+}
+
+pub struct Day14;
+
+impl Solution for Day14 {
+	type Input = Vec<RockPath>;
+	type Answer1 = usize;
+	type Answer2 = usize;
+
+	fn parse(inp: &str) -> Result<Self::Input, ParseError> {
+		common::parse_lines(inp, parse_path)
+	}
+
+	fn part1(paths: &Self::Input) -> usize {
+		build_map(paths, false).part1(|_| ())
+	}
+
+	fn part2(paths: &Self::Input) -> usize {
+		build_map(paths, true).part2(|_| ())
+	}
+}
@@ -0,0 +1,55 @@
+use std::ops::RangeInclusive;
+
+use visual::{Simulation, Visualize};
+
+use crate::{Cell, Coords, Day14, Map, RockPath, build_map};
+
+// Sand settling one unit at a time, each step being a unit coming to rest
+struct Replay {
+	map: Map,
+	settled: Vec<Coords>,
+	xs: RangeInclusive<usize>,
+}
+
+impl Replay {
+	fn new(paths: &[RockPath], part: u8) -> Self {
+		let map = build_map(paths, part == 2);
+		let mut settled = Vec::new();
+		let mut sim = map.clone();
+		match part {
+			1 => sim.part1(|c| settled.push(c)),
+			_ => sim.part2(|c| settled.push(c)),
+		};
+
+		// Only show the columns where something happens, rather than the
+		// whole width of the floor
+		let xs = paths.iter()
+			.flatten()
+			.chain(&settled)
+			.chain([&map.sand])
+			.map(|c| c.x);
+		let min = xs.clone().min().unwrap_or(0).saturating_sub(1);
+		let max = (xs.max().unwrap_or(0) + 1).min(map.map.width() - 1);
+		Self { map, settled, xs: min..=max }
+	}
+}
+
+impl Simulation for Replay {
+	fn steps(&self) -> usize {
+		self.settled.len()
+	}
+
+	fn render(&self, step: usize) -> String {
+		let mut map = self.map.clone();
+		for c in &self.settled[..step] {
+			map.map[*c] = Cell::Sand;
+		}
+		format!("{}\n\n{} units of sand at rest", map.render(self.xs.clone()), step)
+	}
+}
+
+impl Visualize for Day14 {
+	fn simulation(paths: &Self::Input, part: u8) -> Box<dyn Simulation> {
+		Box::new(Replay::new(paths, part))
+	}
+}
@@ -0,0 +1,34 @@
+use common::Solution;
+use day14::Day14;
+
+const EXAMPLE: &str = include_str!("../example.txt");
+
+#[test]
+fn part1() {
+	let input = Day14::parse(EXAMPLE).unwrap();
+	assert_eq!(Day14::part1(&input), 24);
+}
+
+#[test]
+fn part2() {
+	let input = Day14::parse(EXAMPLE).unwrap();
+	assert_eq!(Day14::part2(&input), 93);
+}
+
+#[test]
+fn rocks_far_from_the_source() {
+	// Sand falls past them, then piles up on the floor
+	let input = Day14::parse("10,4 -> 12,4\n").unwrap();
+	assert_eq!(Day14::part1(&input), 0);
+	assert_eq!(Day14::part2(&input), 36);
+	let input = Day14::parse("").unwrap();
+	assert_eq!(Day14::part1(&input), 0);
+	assert_eq!(Day14::part2(&input), 4);
+}
+
+#[test]
+fn rocks_around_the_source() {
+	let input = Day14::parse("499,0 -> 499,2 -> 501,2 -> 501,0\n").unwrap();
+	assert_eq!(Day14::part1(&input), 2);
+	assert_eq!(Day14::part2(&input), 2);
+}
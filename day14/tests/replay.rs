@@ -0,0 +1,18 @@
+use common::Solution;
+use day14::Day14;
+use visual::Visualize;
+
+const EXAMPLE: &str = include_str!("../example.txt");
+
+// The last frame shows every unit of sand counted by the answer
+#[test]
+fn last_frame() {
+	let input = Day14::parse(EXAMPLE).unwrap();
+	for (part, answer) in [(1, 24), (2, 93)] {
+		let sim = Day14::simulation(&input, part);
+		assert_eq!(sim.steps(), answer);
+		let frame = sim.render(sim.steps());
+		let map = frame.split("\n\n").next().unwrap();
+		assert_eq!(map.matches('o').count(), answer, "part {}", part);
+	}
+}
@@ -0,0 +1,106 @@
+use common::{Generate, Rng};
+
+use crate::{Day15, PART2_LIMIT};
+
+// Sensors are placed in rotated coordinates u = x + y and v = x - y, in
+// which their coverage is a square of half-width `r` around (u, v)
+#[derive(Clone, Copy)]
+struct Square {
+	u: i64,
+	v: i64,
+	r: i64,
+}
+
+impl Square {
+	fn covers(&self, u: i64, v: i64) -> bool {
+		self.u.abs_diff(u).max(self.v.abs_diff(v)) <= self.r as u64
+	}
+
+	// Whether the square overlaps the search area, which is the diamond
+	// 0 <= u <= 2 * lim, |v| <= min(u, 2 * lim - u)
+	fn overlaps_area(&self, lim: i64) -> bool {
+		let u = lim.clamp(self.u - self.r, self.u + self.r);
+		let v = 0.clamp(self.v - self.r, self.v + self.r);
+		v.abs() <= u.min(2 * lim - u)
+	}
+
+	// A beacon on the border of the square, with integer x and y
+	fn beacon(&self, rng: &mut Rng) -> (i64, i64) {
+		let mut d = rng.range(-self.r..=self.r);
+		if (d - self.r) % 2 != 0 {
+			d += if d < self.r { 1 } else { -1 };
+		}
+		let e = if rng.chance(0.5) { self.r } else { -self.r };
+		match rng.chance(0.5) {
+			true => (self.u + e, self.v + d),
+			false => (self.u + d, self.v + e),
+		}
+	}
+}
+
+// The distress beacon is boxed in by four squares touching it, and the rest
+// of the search area, up to `lim` on both axes, is tiled with squares around
+// them. Squares whose center would fall between integer points are grown by
+// one.
+pub fn generate_within(rng: &mut Rng, scale: usize, lim: i64) -> String {
+	let count = 30.0 * scale as f64;
+	let side = (lim as f64 * (2.0 / count).sqrt()) as i64;
+	let r = ((side - 1) / 2).max(3) | 1;
+	let side = 2 * r + 1;
+
+	let (gx, gy) = (rng.range(0..=lim), rng.range(0..=lim));
+	let (gu, gv) = (gx + gy, gx - gy);
+
+	let mut squares = vec![
+		Square { u: gu - 1 - r, v: gv, r },
+		Square { u: gu + 1 + r, v: gv, r },
+		Square { u: gu, v: gv - 1 - r, r },
+		Square { u: gu, v: gv + 1 + r, r },
+	];
+
+	let is = (-gu).div_euclid(side) - 1..=(2 * lim - gu) / side + 1;
+	let js = (-lim - gv).div_euclid(side) - 1..=(lim - gv) / side + 1;
+	for i in is {
+		for j in js.clone() {
+			if i == 0 && j == 0 {
+				continue;
+			}
+			let (u, v) = (gu + i * side, gv + j * side);
+			let sq = match (i - j) % 2 == 0 {
+				true => Square { u, v, r },
+				false => Square { u: u + 1, v, r: r + 1 },
+			};
+
+			// Jitter squares a bit while they still cover their tile
+			// and keep clear of the distress beacon
+			let extra = rng.range(0..=r / 4);
+			let du = rng.range(-extra..=extra);
+			let dv = rng.range(-extra..=extra) & !1 | du & 1;
+			let jittered = Square { u: sq.u + du, v: sq.v + dv, r: sq.r + extra };
+			let sq = match jittered.covers(gu, gv) || dv.abs() > extra {
+				true => sq,
+				false => jittered,
+			};
+
+			if sq.overlaps_area(lim) {
+				squares.push(sq);
+			}
+		}
+	}
+	rng.shuffle(&mut squares);
+
+	squares.iter()
+		.map(|sq| {
+			let (bu, bv) = sq.beacon(rng);
+			format!("Sensor at x={}, y={}: closest beacon is at x={}, y={}\n",
+				(sq.u + sq.v) / 2, (sq.u - sq.v) / 2,
+				(bu + bv) / 2, (bu - bv) / 2)
+		})
+		.collect()
+}
+
+impl Generate for Day15 {
+	fn generate(rng: &mut Rng, scale: usize) -> String {
+		generate_within(rng, scale, PART2_LIMIT as i64)
+	}
+}
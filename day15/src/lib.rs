@@ -0,0 +1,201 @@
+use std::cmp;
+use std::collections::HashSet;
+use std::str::FromStr;
+
+use common::{Error, Found, Json, ParseError, Params, Scanner, Solution, ToJson};
+use common::progress;
+use geometry::Point;
+use intervals::{Interval, IntervalSet};
+
+mod generate;
+pub mod reference;
+
+pub use generate::generate_within;
+
+pub type Coords = Point<isize>;
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Sensor {
+	pos: Coords,
+	beacon: Coords,
+	radius: u64,
+}
+
+impl Sensor {
+	/*
+	 * Imagine a sensor with:
+	 *    .pos = (x=5, y=5)
+	 *    .radius = 4
+	 * The sensor coverage looks like this:
+	 *
+	 *   0  1  2  3  4  5  6  7  8  9 10
+	 * 0 .  .  .  .  .  .  .  .  .  .  .
+	 * 1 .  .  .  .  .  #  .  .  .  .  .
+	 * 2 .  .  .  .  #  #  #  .  .  .  .
+	 * 3 .  .  .  #  #  #  #  #  .  .  .
+	 * 4 .  .  #  #  #  #  #  #  #  .  .
+	 * 5 .  #  #  #  #  x  #  #  #  #  .
+	 * 6 .  .  #  #  #  #  #  #  #  .  .
+	 * 7 .  .  .  #  #  #  #  #  .  .  .
+	 * 8 .  .  .  .  #  #  #  .  .  .  .
+	 * 9 .  .  .  .  .  #  .  .  .  .  .
+	 *
+	 * This function gives, for a given y, the sensor's
+	 * coverage at that row.
+	 * E.g.:
+	 *  - For y = 7, the following line is returned:
+	 *    start = 3, end = 7
+	 *  - For y = 0, None is returned
+	 */
+	pub fn coverage_at(&self, y: isize) -> Option<Interval> {
+		let ydiff = y.abs_diff(self.pos.y) as u64;
+		if ydiff > self.radius {
+			return None;
+		}
+		let radius = (self.radius - ydiff) as isize;
+		Some(Interval::new(
+			(self.pos.x - radius) as i64,
+			(self.pos.x + radius) as i64,
+		))
+	}
+}
+
+// The puzzle's row and search area, unless configured otherwise
+pub const PART1_ROW: isize = 2000000;
+pub const PART2_LIMIT: isize = 4000000;
+
+pub fn part1(sensors: &[Sensor], row: isize) -> u64 {
+	let covered = sensors.iter()
+		.filter_map(|s| s.coverage_at(row))
+		.collect::<IntervalSet>();
+
+	let beacons = sensors.iter()
+		.filter(|s| s.beacon.y == row && covered.contains(s.beacon.x as i64))
+		.map(|s| s.beacon)
+		.collect::<HashSet<_>>();
+
+	covered.len() - beacons.len() as u64
+}
+
+// The tuning frequency of the only position within the search area that no
+// sensor covers, if there is one
+pub fn part2(sensors: &[Sensor], lim: isize) -> Option<usize> {
+	let mut free = IntervalSet::new();
+
+	for y in 0..=lim {
+		progress::report(y as u64, Some(lim as u64 + 1), "rows");
+		free.clear();
+		free.insert(Interval::new(0, lim as i64));
+		for sensor in sensors.iter() {
+			if let Some(cv) = sensor.coverage_at(y) {
+				free.remove(cv);
+				if free.is_empty() {
+					break;
+				}
+			}
+		}
+
+		if let Some(gap) = free.iter().next() {
+			return Some((gap.start as isize * 4000000 + y) as usize);
+		}
+	}
+
+	None
+}
+
+// Keeps distances, coverage bounds and the tuning frequency from overflowing
+const MAX_COORD: isize = 1 << 40;
+
+fn scan_coord(sc: &mut Scanner) -> Result<isize, ParseError> {
+	let err = sc.error(format!("a coordinate within ±{}", MAX_COORD));
+	match sc.number::<isize>()? {
+		v if v.unsigned_abs() <= MAX_COORD as usize => Ok(v),
+		_ => Err(err),
+	}
+}
+
+fn scan_coords(sc: &mut Scanner) -> Result<Coords, ParseError> {
+	sc.tag("x=")?;
+	let x = scan_coord(sc)?;
+	sc.tag(", y=")?;
+	let y = scan_coord(sc)?;
+	Ok(Coords::new(x, y))
+}
+
+impl ToJson for Sensor {
+	fn to_json(&self) -> Json {
+		Json::object([
+			("pos", self.pos.to_json()),
+			("beacon", self.beacon.to_json()),
+			("radius", self.radius.to_json()),
+		])
+	}
+}
+
+impl FromStr for Sensor {
+	type Err = ParseError;
+	fn from_str(st: &str) -> Result<Self, Self::Err> {
+		let mut sc = Scanner::new(st);
+		sc.tag("Sensor at ")?;
+		let pos = scan_coords(&mut sc)?;
+		sc.tag(": closest beacon is at ")?;
+		let beacon = scan_coords(&mut sc)?;
+		sc.end()?;
+
+		let radius = pos.manhattan(beacon);
+		Ok(Self { pos, beacon, radius })
+	}
+}
+
+pub struct Scan {
+	pub sensors: Vec<Sensor>,
+	// The row checked in part 1, and the bound of the search area in part 2
+	pub row: isize,
+	pub limit: isize,
+}
+
+impl ToJson for Scan {
+	fn to_json(&self) -> Json {
+		Json::object([
+			("sensors", self.sensors.to_json()),
+			("row", self.row.to_json()),
+			("limit", self.limit.to_json()),
+		])
+	}
+}
+
+pub struct Day15;
+
+impl Solution for Day15 {
+	type Input = Scan;
+	type Answer1 = u64;
+	type Answer2 = Found<usize>;
+
+	fn parse(inp: &str) -> Result<Self::Input, ParseError> {
+		let mut sensors = common::parse_lines(inp, Sensor::from_str)?;
+
+		sensors.sort_by_key(|s| cmp::Reverse(s.radius));
+		Ok(Scan { sensors, row: PART1_ROW, limit: PART2_LIMIT })
+	}
+
+	fn part1(scan: &Self::Input) -> u64 {
+		part1(&scan.sensors, scan.row)
+	}
+
+	fn part2(scan: &Self::Input) -> Found<usize> {
+		Found(part2(&scan.sensors, scan.limit))
+	}
+
+	fn configure(scan: &mut Self::Input, params: &Params) -> Result<(), Error> {
+		params.expect(&["row", "limit"])?;
+		params.apply("row", -MAX_COORD, &mut scan.row)?;
+		params.apply("limit", 0, &mut scan.limit)?;
+		// Like coordinates, so that neither distances nor the tuning
+		// frequency overflow
+		match (scan.row <= MAX_COORD, scan.limit <= MAX_COORD) {
+			(false, _) => Err(format!("row must be at most {}", MAX_COORD).into()),
+			(_, false) => Err(format!("limit must be at most {}", MAX_COORD).into()),
+			_ => Ok(()),
+		}
+	}
+}
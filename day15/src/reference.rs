@@ -0,0 +1,25 @@
+// Cell-by-cell coverage checks, to verify the interval arithmetic of
+// `part1()` and `part2()` against. Only practical on small areas.
+
+use crate::{Coords, Sensor};
+
+fn covered(sensors: &[Sensor], p: Coords) -> bool {
+	sensors.iter().any(|s| s.pos.manhattan(p) <= s.radius)
+}
+
+pub fn part1(sensors: &[Sensor], row: isize) -> u64 {
+	let min = sensors.iter().map(|s| s.pos.x - s.radius as isize).min().unwrap();
+	let max = sensors.iter().map(|s| s.pos.x + s.radius as isize).max().unwrap();
+	(min..=max)
+		.map(|x| Coords::new(x, row))
+		.filter(|p| covered(sensors, *p))
+		.filter(|p| sensors.iter().all(|s| s.beacon != *p))
+		.count() as u64
+}
+
+pub fn part2(sensors: &[Sensor], lim: isize) -> Option<usize> {
+	(0..=lim)
+		.flat_map(|y| (0..=lim).map(move |x| Coords::new(x, y)))
+		.find(|p| !covered(sensors, *p))
+		.map(|p| (p.x * 4000000 + p.y) as usize)
+}
@@ -0,0 +1,19 @@
+use common::{Found, Solution};
+use day15::Day15;
+
+const EXAMPLE: &str = include_str!("../example.txt");
+
+// The example uses a much smaller row and search area than the real input
+#[test]
+fn part1() {
+	let mut input = Day15::parse(EXAMPLE).unwrap();
+	input.row = 10;
+	assert_eq!(Day15::part1(&input), 26);
+}
+
+#[test]
+fn part2() {
+	let mut input = Day15::parse(EXAMPLE).unwrap();
+	input.limit = 20;
+	assert_eq!(Day15::part2(&input), Found(Some(56000011)));
+}
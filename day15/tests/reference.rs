@@ -0,0 +1,36 @@
+use common::{Rng, Solution};
+use day15::{Day15, reference};
+
+const LIMIT: isize = 60;
+
+#[test]
+fn coverage_matches_cell_by_cell() {
+	for seed in 0..50 {
+		let inp = day15::generate_within(&mut Rng::new(seed), 1, LIMIT as i64);
+		let sensors = Day15::parse(&inp).unwrap().sensors;
+
+		assert_eq!(day15::part1(&sensors, LIMIT / 2),
+			reference::part1(&sensors, LIMIT / 2), "seed {}", seed);
+		assert_eq!(day15::part2(&sensors, LIMIT),
+			reference::part2(&sensors, LIMIT), "seed {}", seed);
+	}
+}
+
+// Sparse sensors leave gaps in the row, unlike generated inputs
+#[test]
+fn row_coverage_matches_cell_by_cell() {
+	for seed in 0..200 {
+		let mut rng = Rng::new(seed);
+		let inp: String = (0..rng.range(1..=6))
+			.map(|_| {
+				let [sx, sy, bx, by] = [(); 4].map(|_| rng.range(-20..=80));
+				format!("Sensor at x={}, y={}: closest beacon is at x={}, y={}\n",
+					sx, sy, bx, by)
+			})
+			.collect();
+		let sensors = Day15::parse(&inp).unwrap().sensors;
+
+		assert_eq!(day15::part1(&sensors, LIMIT / 2),
+			reference::part1(&sensors, LIMIT / 2), "seed {}", seed);
+	}
+}
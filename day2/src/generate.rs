@@ -0,0 +1,13 @@
+use common::{Generate, Rng};
+
+use crate::Day2;
+
+impl Generate for Day2 {
+	fn generate(rng: &mut Rng, scale: usize) -> String {
+		(0..2500 * scale)
+			.map(|_| format!("{} {}\n",
+				rng.choose(&['A', 'B', 'C']),
+				rng.choose(&['X', 'Y', 'Z'])))
+			.collect()
+	}
+}
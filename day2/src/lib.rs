@@ -0,0 +1,192 @@
+use std::str;
+
+use common::{Json, ParseError, Scanner, Solution, ToJson};
+
+mod generate;
+
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum MatchResult {
+	Win,
+	Loss,
+	Draw,
+}
+
+impl MatchResult {
+	fn points(&self) -> u64 {
+		match self {
+			Self::Win => 6,
+			Self::Draw => 3,
+			Self::Loss => 0,
+		}
+	}
+
+	fn rev(&self) -> MatchResult {
+		match self {
+			Self::Win => Self::Loss,
+			Self::Loss => Self::Win,
+			Self::Draw => Self::Draw,
+		}
+	}
+}
+
+impl ToJson for MatchResult {
+	fn to_json(&self) -> Json {
+		let name = match self {
+			Self::Win => "win",
+			Self::Loss => "loss",
+			Self::Draw => "draw",
+		};
+		name.to_json()
+	}
+}
+
+impl str::FromStr for MatchResult {
+	type Err = ParseError;
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		match s {
+			"X" => Ok(Self::Loss),
+			"Y" => Ok(Self::Draw),
+			"Z" => Ok(Self::Win),
+			_ => Err(ParseError::new(0, s, "X, Y or Z")),
+		}
+	}
+}
+
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum Shape {
+	Rock,
+	Paper,
+	Scissors,
+}
+
+impl ToJson for Shape {
+	fn to_json(&self) -> Json {
+		let name = match self {
+			Self::Rock => "rock",
+			Self::Paper => "paper",
+			Self::Scissors => "scissors",
+		};
+		name.to_json()
+	}
+}
+
+impl str::FromStr for Shape {
+	type Err = ParseError;
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		match s {
+			"A" | "X" => Ok(Self::Rock),
+			"B" | "Y" => Ok(Self::Paper),
+			"C" | "Z" => Ok(Self::Scissors),
+			_ => Err(ParseError::new(0, s, "a shape")),
+		}
+	}
+}
+
+impl Shape {
+	fn points(&self) -> u64 {
+		match self {
+			Self::Rock => 1,
+			Self::Paper => 2,
+			Self::Scissors => 3,
+		}
+	}
+
+	fn game(&self, other: &Self) -> MatchResult {
+		match self {
+			_ if self.win()  == *other => MatchResult::Win,
+			_ if self.draw() == *other => MatchResult::Draw,
+			_ if self.loss() == *other => MatchResult::Loss,
+			_ => unreachable!(),
+		}
+	}
+
+	fn game_points(&self, other: &Self) -> u64 {
+		self.points() + self.game(other).points()
+	}
+
+	fn arrange_result(&self, r: &MatchResult) -> Shape {
+		match r {
+			MatchResult::Win => self.win(),
+			MatchResult::Draw => self.draw(),
+			MatchResult::Loss => self.loss(),
+		}
+	}
+
+	fn win(&self) -> Shape {
+		match self {
+			Self::Rock => Self::Scissors,
+			Self::Paper => Self::Rock,
+			Self::Scissors => Self::Paper,
+		}
+	}
+
+	fn draw(&self) -> Shape {
+		*self
+	}
+
+	fn loss(&self) -> Shape {
+		self.win().win()
+	}
+}
+
+fn parse_line1(line: &str) -> Result<(Shape, Shape), ParseError> {
+	let mut sc = Scanner::new(line);
+	let other = sc.parse::<Shape>()?;
+	sc.tag(" ")?;
+	let ours = sc.parse::<Shape>()?;
+	sc.end()?;
+	Ok((other, ours))
+}	
+
+fn parse_line2(line: &str) -> Result<(Shape, MatchResult), ParseError> {
+	let mut sc = Scanner::new(line);
+	let other = sc.parse::<Shape>()?;
+	sc.tag(" ")?;
+	let res = sc.parse::<MatchResult>()?;
+	sc.end()?;
+	Ok((other, res))
+}	
+
+pub struct Strategy {
+	guide1: Vec<(Shape, Shape)>,
+	guide2: Vec<(Shape, MatchResult)>,
+}
+
+// Both readings of the guide, as pairs of the opponent's shape and either
+// ours or the result
+impl ToJson for Strategy {
+	fn to_json(&self) -> Json {
+		Json::object([
+			("guide1", self.guide1.to_json()),
+			("guide2", self.guide2.to_json()),
+		])
+	}
+}
+
+pub struct Day2;
+
+impl Solution for Day2 {
+	type Input = Strategy;
+	type Answer1 = u64;
+	type Answer2 = u64;
+
+	fn parse(inp: &str) -> Result<Self::Input, ParseError> {
+		let guide1 = common::parse_lines(inp, parse_line1)?;
+		let guide2 = common::parse_lines(inp, parse_line2)?;
+
+		Ok(Strategy { guide1, guide2 })
+	}
+
+	fn part1(strategy: &Self::Input) -> u64 {
+		strategy.guide1.iter()
+			.map(|(other, ours)| ours.game_points(other))
+			.sum::<u64>()
+	}
+
+	fn part2(strategy: &Self::Input) -> u64 {
+		strategy.guide2.iter()
+			.map(|(other, res)|
+				other.arrange_result(&res.rev()).points() + res.points())
+			.sum::<u64>()
+	}
+}
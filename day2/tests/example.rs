@@ -0,0 +1,16 @@
+use common::Solution;
+use day2::Day2;
+
+const EXAMPLE: &str = include_str!("../example.txt");
+
+#[test]
+fn part1() {
+	let input = Day2::parse(EXAMPLE).unwrap();
+	assert_eq!(Day2::part1(&input), 15);
+}
+
+#[test]
+fn part2() {
+	let input = Day2::parse(EXAMPLE).unwrap();
+	assert_eq!(Day2::part2(&input), 12);
+}
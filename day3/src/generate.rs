@@ -0,0 +1,55 @@
+use common::{Generate, Rng};
+
+use crate::Day3;
+
+const ITEMS: &[u8] = b"abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ";
+
+// A rucksack whose halves share only `shared`, built from `owned` items
+// plus `badge` somewhere
+fn rucksack(rng: &mut Rng, owned: &[u8], shared: u8, badge: u8) -> String {
+	let mut rest: Vec<u8> = owned.iter()
+		.copied()
+		.filter(|c| *c != shared)
+		.collect();
+	rng.shuffle(&mut rest);
+	let (left, right) = rest.split_at(rest.len() / 2);
+
+	let half = rng.range(6..=16) as usize;
+	let mut halves = [left, right].map(|items| {
+		let mut h: Vec<u8> = (1..half).map(|_| *rng.choose(items)).collect();
+		h.push(shared);
+		rng.shuffle(&mut h);
+		h
+	});
+	if badge != shared {
+		let h = rng.below(2);
+		let pos = halves[h].iter().position(|c| *c != shared).unwrap();
+		halves[h][pos] = badge;
+	}
+	halves.concat().into_iter().map(char::from).collect()
+}
+
+impl Generate for Day3 {
+	// Within a group, every item but the badge belongs to a single
+	// rucksack, so the badge is the only item the three have in common
+	fn generate(rng: &mut Rng, scale: usize) -> String {
+		let mut out = String::new();
+		for _ in 0..100 * scale {
+			let badge = *rng.choose(ITEMS);
+			let mut items: Vec<u8> = ITEMS.iter()
+				.copied()
+				.filter(|c| *c != badge)
+				.collect();
+			rng.shuffle(&mut items);
+			for owned in items.chunks(items.len() / 3) {
+				let shared = match rng.chance(0.2) {
+					true => badge,
+					false => *rng.choose(owned),
+				};
+				out += &rucksack(rng, owned, shared, badge);
+				out.push('\n');
+			}
+		}
+		out
+	}
+}
@@ -0,0 +1,106 @@
+use std::collections::HashSet;
+use std::str::{self, FromStr};
+
+use common::{Json, ParseError, Solution, ToJson};
+
+mod generate;
+
+
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct Item {
+	item: char,
+}
+
+impl Item {
+	fn new(item: char) -> Self {
+		Self { item }
+	}
+
+	pub fn prio(&self) -> u64 {
+		(match self.item.is_lowercase() {
+			true => (self.item as u8) - 96,
+			false => (self.item as u8) - 38,
+		}) as u64
+	}
+}
+
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+pub struct Rucksack {
+	c1: Vec<Item>,
+	c2: Vec<Item>,
+}
+
+impl Rucksack {
+	pub fn iter(&self) -> impl Iterator<Item = Item> + '_ {
+		self.c1.iter().chain(self.c2.iter()).copied()
+	}
+
+	pub fn inner_intersection(&self) -> Item {
+		let c1 = HashSet::<&Item>::from_iter(self.c1.iter());
+		let c2 = HashSet::<&Item>::from_iter(self.c2.iter());
+		**(c1.intersection(&c2).next().unwrap())
+	}
+
+	fn intersection2(&self, other1: &Self, other2: &Self) -> Item {
+		let c1 = HashSet::<Item>::from_iter(self.iter());
+		let c2 = HashSet::<Item>::from_iter(other1.iter());
+		let c3 = HashSet::<Item>::from_iter(other2.iter());
+
+		let c12 = HashSet::<Item>::from_iter(c1.intersection(&c2)
+			.copied());
+		
+		*c12.intersection(&c3).next().unwrap()
+	}
+}
+
+// Each compartment as a string of items
+impl ToJson for Rucksack {
+	fn to_json(&self) -> Json {
+		let items = |c: &[Item]| c.iter().map(|i| i.item).collect::<String>();
+		Json::Array(vec![items(&self.c1).to_json(), items(&self.c2).to_json()])
+	}
+}
+
+impl str::FromStr for Rucksack {
+	type Err = ParseError;
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		if let Some((col, c)) = s.chars()
+			.enumerate()
+			.find(|(_, c)| !c.is_ascii_alphabetic())
+		{
+			return Err(ParseError::new(col, &c.to_string(), "an item letter"));
+		}
+		if !s.len().is_multiple_of(2) {
+			return Err(ParseError::new(0, s, "an even number of items"));
+		}
+
+		let half = s.len() / 2;
+		let c1 = &s[..half].chars().map(Item::new).collect::<Vec<_>>();
+		let c2 = &s[half..].chars().map(Item::new).collect::<Vec<_>>();
+		Ok(Self { c1: c1.to_vec(), c2: c2.to_vec() })
+	}
+}
+
+pub struct Day3;
+
+impl Solution for Day3 {
+	type Input = Vec<Rucksack>;
+	type Answer1 = u64;
+	type Answer2 = u64;
+
+	fn parse(inp: &str) -> Result<Self::Input, ParseError> {
+		common::parse_lines(inp, Rucksack::from_str)
+	}
+
+	fn part1(rs: &Self::Input) -> u64 {
+		rs.iter()
+			.map(|r| r.inner_intersection().prio())
+			.sum::<u64>()
+	}
+
+	fn part2(rs: &Self::Input) -> u64 {
+		rs.chunks(3)
+			.map(|grp| grp[0].intersection2(&grp[1], &grp[2]).prio())
+			.sum::<u64>()
+	}
+}
@@ -0,0 +1,17 @@
+use common::{Generate, Rng};
+
+use crate::Day4;
+
+fn range(rng: &mut Rng) -> String {
+	let start = rng.range(1..=99);
+	let end = rng.range(start..=99);
+	format!("{}-{}", start, end)
+}
+
+impl Generate for Day4 {
+	fn generate(rng: &mut Rng, scale: usize) -> String {
+		(0..1000 * scale)
+			.map(|_| format!("{},{}\n", range(rng), range(rng)))
+			.collect()
+	}
+}
@@ -0,0 +1,84 @@
+use std::str;
+
+use common::{Json, ParseError, Scanner, Solution, ToJson};
+use intervals::Interval;
+
+mod generate;
+
+// A section assignment, such as `2-4`
+#[derive(Debug)]
+pub struct Range(Interval);
+
+impl Range {
+	pub fn contains(&self, other: &Self) -> bool {
+		self.0.contains_interval(&other.0)
+	}
+
+	pub fn overlaps(&self, other: &Self) -> bool {
+		self.0.overlaps(&other.0)
+	}
+}
+
+impl Range {
+	fn scan(sc: &mut Scanner) -> Result<Self, ParseError> {
+		let start = sc.number::<i64>()?;
+		sc.tag("-")?;
+		let col = sc.col();
+		let end = sc.number::<i64>()?;
+		if end < start {
+			return Err(ParseError::new(col, &end.to_string(),
+				format!("a section number of at least {}", start)));
+		}
+		Ok(Self(Interval::new(start, end)))
+	}
+}
+
+// The first and last sections, both included
+impl ToJson for Range {
+	fn to_json(&self) -> Json {
+		Json::Array(vec![self.0.start.to_json(), self.0.end.to_json()])
+	}
+}
+
+impl str::FromStr for Range {
+	type Err = ParseError;
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		let mut sc = Scanner::new(s);
+		let range = Self::scan(&mut sc)?;
+		sc.end()?;
+		Ok(range)
+	}
+}
+
+fn parse_line(s: &str) -> Result<(Range, Range), ParseError> {
+	let mut sc = Scanner::new(s);
+	let p1 = Range::scan(&mut sc)?;
+	sc.tag(",")?;
+	let p2 = Range::scan(&mut sc)?;
+	sc.end()?;
+	Ok((p1, p2))
+}
+
+pub struct Day4;
+
+impl Solution for Day4 {
+	type Input = Vec<(Range, Range)>;
+	type Answer1 = usize;
+	type Answer2 = usize;
+
+	fn parse(inp: &str) -> Result<Self::Input, ParseError> {
+		common::parse_lines(inp, parse_line)
+	}
+
+	fn part1(rs: &Self::Input) -> usize {
+		rs.iter()
+			.filter(|(a, b)| a.contains(b) || b.contains(a))
+			.count()
+	}
+
+	fn part2(rs: &Self::Input) -> usize {
+		rs.iter()
+			.filter(|(a, b)| a.overlaps(b))
+			.count()
+	}
+}
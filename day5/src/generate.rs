@@ -0,0 +1,49 @@
+use common::{Generate, Rng};
+use common::rng::scale_side;
+
+use crate::Day5;
+
+const COLUMNS: usize = 9;
+
+impl Generate for Day5 {
+	// Moves are simulated as they are generated, so that no move ever
+	// takes more crates than its source column holds
+	fn generate(rng: &mut Rng, scale: usize) -> String {
+		let max_height = scale_side(8, scale) as i64;
+		let mut columns: Vec<Vec<char>> = (0..COLUMNS)
+			.map(|_| (0..rng.range(1..=max_height))
+				.map(|_| rng.range(b'A' as i64..=b'Z' as i64) as u8 as char)
+				.collect())
+			.collect();
+
+		let height = columns.iter().map(|c| c.len()).max().unwrap();
+		let mut out: String = (0..height).rev()
+			.map(|level| columns.iter()
+				.map(|c| c.get(level)
+					.map(|cr| format!("[{}]", cr))
+					.unwrap_or_else(|| "   ".to_string()))
+				.collect::<Vec<_>>()
+				.join(" ") + "\n")
+			.collect();
+		out += &(1..=COLUMNS)
+			.map(|n| format!(" {} ", n))
+			.collect::<Vec<_>>()
+			.join(" ");
+		out += "\n\n";
+
+		for _ in 0..500 * scale {
+			let nonempty: Vec<_> = (0..COLUMNS)
+				.filter(|c| !columns[*c].is_empty())
+				.collect();
+			let from = *rng.choose(&nonempty);
+			let to = (from + 1 + rng.below(COLUMNS - 1)) % COLUMNS;
+			let amnt = rng.range(1..=columns[from].len().min(30) as i64) as usize;
+
+			let at = columns[from].len() - amnt;
+			let moved = columns[from].split_off(at);
+			columns[to].extend(moved);
+			out += &format!("move {} from {} to {}\n", amnt, from + 1, to + 1);
+		}
+		out
+	}
+}
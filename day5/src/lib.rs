@@ -0,0 +1,310 @@
+use std::fmt;
+use std::str;
+use std::ops::{Deref, DerefMut};
+
+use common::{Json, ParseError, Scanner, Solution, ToJson};
+
+mod generate;
+mod replay;
+
+// Each row is made of 3-character fields separated by a space, with
+// blank fields for missing crates
+fn parse_line_crates(st: &str) -> Result<Vec<Option<Crate>>, ParseError> {
+	let chars = st.chars().collect::<Vec<_>>();
+	let mut fields = Vec::new();
+
+	for (i, field) in chars.chunks(4).enumerate() {
+		let col = i * 4;
+		let (field, sep) = field.split_at(field.len().min(3));
+		let field = String::from_iter(field);
+		if sep.iter().any(|c| *c != ' ') {
+			return Err(ParseError::new(col + 3, &String::from_iter(sep), "` `"));
+		}
+		match field.trim().is_empty() {
+			true => fields.push(None),
+			false => fields.push(Some(field.parse::<Crate>()
+				.map_err(|e| e.at_col(col))?)),
+		}
+	}
+
+	Ok(fields)
+}
+
+// Parses the row of column numbers below the crates, returning how many
+// columns there are
+fn parse_line_numbers(st: &str) -> Result<usize, ParseError> {
+	let mut sc = Scanner::new(st);
+	let mut width = 0;
+
+	loop {
+		sc.skip_whitespace();
+		if sc.is_empty() {
+			return Ok(width);
+		}
+		let col = sc.col();
+		let num = sc.number::<usize>()?;
+		if num != width + 1 {
+			return Err(ParseError::new(col, &num.to_string(),
+				format!("column number {}", width + 1)));
+		}
+		width += 1;
+	}
+}
+
+#[derive(Debug, Clone)]
+pub struct Drawing {
+	columns: Vec<Column>
+}
+
+impl Drawing {
+	pub fn exec(&mut self, movs: &[Movement]) -> &Self {
+		for mov in movs.iter() {
+			for _ in 0..mov.amnt {
+				let cr = self.columns[mov.from]
+					.pop().unwrap();
+				self.columns[mov.to].push(cr);
+			}
+		}
+		self
+	}
+
+	pub fn exec_9001(&mut self, movs: &[Movement]) -> &Self {
+		for mov in movs.iter() {
+			let cr = self.columns[mov.from]
+				.pop_multi(mov.amnt).unwrap();
+			self.columns[mov.to].push_multi(cr);
+		}
+		self
+	}
+
+	pub fn fmt(&self) -> String {
+		let depth = self.columns.iter()
+			.map(|col| col.len())
+			.max().unwrap();
+
+		(0..depth)
+			.map(|row| self.columns.iter()
+				.map(|col| col.get(row)
+					.map(|cr| cr.to_string())
+					.unwrap_or_else(|| "[_]".to_string()))
+				.collect::<Vec<_>>()
+				.join(" "))
+			.rev()
+			.collect::<Vec<_>>()
+			.join("\n")
+	}
+
+	pub fn msg(&self) -> String {
+		self.columns.iter()
+			.filter_map(|col| col.last())
+			.map(|cr| String::from(cr.name))
+			.collect::<Vec<_>>()
+			.join("")
+	}
+}
+
+// The crates of each column, from the bottom up
+impl ToJson for Drawing {
+	fn to_json(&self) -> Json {
+		Json::Array(self.columns.iter()
+			.map(|col| Json::Array(col.iter().map(|cr| cr.name.to_json()).collect()))
+			.collect())
+	}
+}
+
+impl str::FromStr for Drawing {
+	type Err = ParseError;
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		// The last row holds the column numbers
+		let mut rows = common::lines(s).collect::<Vec<_>>();
+		let (numline, numbers) = rows.pop()
+			.ok_or_else(|| ParseError::new(0, "", "a drawing of crates"))?;
+		let width = parse_line_numbers(numbers)
+			.map_err(|e| e.at_line(numline))?;
+
+		// Parse out all crates in each row, except last one
+		let rows = rows.into_iter()
+			.map(|(i, row)| {
+				let crates = parse_line_crates(row)
+					.map_err(|e| e.at_line(i))?;
+				match crates.len() > width {
+					true => Err(ParseError::new(width * 4,
+						&row.chars().skip(width * 4).collect::<String>(),
+						"end of line").at_line(i)),
+					false => Ok(crates),
+				}
+			})
+			.collect::<Result<Vec<_>, _>>()?;
+
+		// Prepare columns
+		let mut columns = (0..width)
+			.map(|_| Column::new())
+			.collect::<Vec<_>>();
+
+		// Transpose rows into columns
+		for row in rows.into_iter() {
+			for (i, cr) in row.into_iter().enumerate() {
+				if let Some(v) = cr {
+					columns[i].push(v);
+				}
+			}
+		}
+
+		for col in columns.iter_mut() {
+			col.reverse();
+		}
+
+		Ok(Self { columns })
+	}
+}
+
+#[derive(Debug, Clone)]
+pub struct Column(Vec<Crate>);
+
+impl Deref for Column {
+	type Target = Vec<Crate>;
+	fn deref(&self) -> &Self::Target { &self.0 }
+}
+
+impl DerefMut for Column {
+	fn deref_mut(&mut self) -> &mut Self::Target { &mut self.0 }
+}
+
+impl Column {
+	fn new() -> Self {
+		Self(<Self as Deref>::Target::new())
+	}
+
+	fn pop_multi(&mut self, n: usize) -> Option<Vec<Crate>> {
+		let mut out = (0..n).map(|_| self.pop())
+			.collect::<Option<Vec<_>>>()?;
+		out.reverse();
+		Some(out)
+	}
+
+	fn push_multi(&mut self, v: Vec<Crate>) {
+		for e in v.into_iter() {
+			self.push(e);
+		}
+	}
+}
+
+#[derive(Debug, Copy, Clone)]
+pub struct Crate {
+	name: char,
+}
+
+impl str::FromStr for Crate {
+	type Err = ParseError;
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		let chrs = s.chars().collect::<Vec<_>>();
+		match chrs[..] {
+			['[', name, ']'] if !name.is_whitespace() => Ok(Self { name }),
+			_ => Err(ParseError::new(0, s, "a crate such as `[A]`")),
+		}
+	}
+}
+
+impl fmt::Display for Crate {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		write!(f, "[{}]", self.name)
+	}
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct Movement {
+	amnt: usize,
+	from: usize,
+	to: usize,
+}
+
+// Columns are numbered from 1, as in the input
+impl ToJson for Movement {
+	fn to_json(&self) -> Json {
+		Json::object([
+			("amount", self.amnt.to_json()),
+			("from", (self.from + 1).to_json()),
+			("to", (self.to + 1).to_json()),
+		])
+	}
+}
+
+// Parses a 1-based column number into an index
+fn scan_column(sc: &mut Scanner) -> Result<usize, ParseError> {
+	let col = sc.col();
+	match sc.number::<usize>()? {
+		0 => Err(ParseError::new(col, "0", "a column number")),
+		n => Ok(n - 1),
+	}
+}
+
+impl str::FromStr for Movement {
+	type Err = ParseError;
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		let mut sc = Scanner::new(s);
+		sc.tag("move ")?;
+		let amnt = sc.number::<usize>()?;
+		sc.tag(" from ")?;
+		let from = scan_column(&mut sc)?;
+		sc.tag(" to ")?;
+		let to = scan_column(&mut sc)?;
+		sc.end()?;
+		Ok(Self { amnt, from, to })
+	}
+}
+
+#[derive(Debug, Clone)]
+pub struct Procedure {
+	pub drawing: Drawing,
+	moves: Vec<Movement>,
+}
+
+impl ToJson for Procedure {
+	fn to_json(&self) -> Json {
+		Json::object([
+			("drawing", self.drawing.to_json()),
+			("moves", self.moves.to_json()),
+		])
+	}
+}
+
+pub struct Day5;
+
+impl Solution for Day5 {
+	type Input = Procedure;
+	type Answer1 = String;
+	type Answer2 = String;
+
+	fn parse(inp: &str) -> Result<Self::Input, ParseError> {
+		let mut parts = common::blocks(inp);
+		let missing = |what| ParseError::new(0, "", what)
+			.at_line(inp.matches('\n').count());
+
+		let (start, drawing) = parts.next()
+			.ok_or_else(|| missing("a drawing of crates"))?;
+		let drawing = drawing.parse::<Drawing>()
+			.map_err(|e| e.at_line(start))?;
+
+		let (start, moves) = parts.next()
+			.ok_or_else(|| missing("a list of movements"))?;
+		let width = drawing.columns.len();
+		let moves = common::parse_lines(moves, |line| {
+			let mov = line.parse::<Movement>()?;
+			match mov.from.max(mov.to) < width {
+				true => Ok(mov),
+				false => Err(ParseError::new(0, line,
+					format!("columns between 1 and {}", width))),
+			}
+		}).map_err(|e| e.at_line(start))?;
+
+		Ok(Procedure { drawing, moves })
+	}
+
+	fn part1(proc: &Self::Input) -> String {
+		proc.drawing.clone().exec(&proc.moves).msg()
+	}
+
+	fn part2(proc: &Self::Input) -> String {
+		proc.drawing.clone().exec_9001(&proc.moves).msg()
+	}
+}
@@ -0,0 +1,46 @@
+use visual::{Simulation, Visualize};
+
+use crate::{Day5, Drawing, Movement, Procedure};
+
+// The crates after each movement of the procedure
+struct Replay {
+	states: Vec<Drawing>,
+	moves: Vec<Movement>,
+}
+
+impl Replay {
+	fn new(proc: &Procedure, part: u8) -> Self {
+		let mut drawing = proc.drawing.clone();
+		let mut states = vec![drawing.clone()];
+		for mov in proc.moves.chunks(1) {
+			match part {
+				1 => drawing.exec(mov),
+				_ => drawing.exec_9001(mov),
+			};
+			states.push(drawing.clone());
+		}
+		Self { states, moves: proc.moves.clone() }
+	}
+}
+
+impl Simulation for Replay {
+	fn steps(&self) -> usize {
+		self.moves.len()
+	}
+
+	fn render(&self, step: usize) -> String {
+		let last = match step.checked_sub(1).map(|i| &self.moves[i]) {
+			Some(mov) => format!("move {} from {} to {}",
+				mov.amnt, mov.from + 1, mov.to + 1),
+			None => "start".to_string(),
+		};
+		let drawing = &self.states[step];
+		format!("{}\n\n{}\ntop: {}", drawing.fmt(), last, drawing.msg())
+	}
+}
+
+impl Visualize for Day5 {
+	fn simulation(proc: &Self::Input, part: u8) -> Box<dyn Simulation> {
+		Box::new(Replay::new(proc, part))
+	}
+}
@@ -0,0 +1,30 @@
+use common::Solution;
+use day5::Day5;
+
+const EXAMPLE: &str = include_str!("../example.txt");
+
+#[test]
+fn part1() {
+	let input = Day5::parse(EXAMPLE).unwrap();
+	assert_eq!(Day5::part1(&input), "CMZ");
+}
+
+#[test]
+fn part2() {
+	let input = Day5::parse(EXAMPLE).unwrap();
+	assert_eq!(Day5::part2(&input), "MCD");
+}
+
+// Normalizing trims the trailing blanks of the drawing, which the parser
+// must not depend on
+#[test]
+fn normalized() {
+	let raw = format!("\u{feff}{}", EXAMPLE.replace('\n', " \r\n"));
+	let (inp, fixes) = common::input::normalize(&raw);
+	assert_eq!(fixes.len(), 3, "{:?}", fixes);
+	assert!(inp.lines().all(|line| !line.ends_with(' ')));
+
+	let input = Day5::parse(&inp).unwrap();
+	assert_eq!(Day5::part1(&input), "CMZ");
+	assert_eq!(Day5::part2(&input), "MCD");
+}
@@ -0,0 +1,27 @@
+use common::{Generate, Rng};
+
+use crate::Day6;
+
+const LETTERS: &[u8] = b"abcdefghijklmnopqrstuvwxyz";
+
+impl Generate for Day6 {
+	// The text starts with only three letters, so that the start-of-packet
+	// marker comes a while in. Text before the start-of-message marker only
+	// uses ten letters, so the first run of 14 distinct ones is the marker
+	// we plant.
+	fn generate(rng: &mut Rng, scale: usize) -> String {
+		let len = 4096 * scale as i64;
+		let mut letters = LETTERS.to_vec();
+		rng.shuffle(&mut letters);
+
+		let packet = rng.range(len / 8..=len / 4) as usize;
+		let at = rng.range(len / 2..=len - 14) as usize;
+		let mut out = rng.string(&letters[..3], packet);
+		out += &rng.string(&letters[..10], at - packet);
+		rng.shuffle(&mut letters);
+		out.extend(letters[..14].iter().map(|c| *c as char));
+		out += &rng.string(LETTERS, len as usize - at - 14);
+		out.push('\n');
+		out
+	}
+}
@@ -0,0 +1,38 @@
+use std::collections::HashSet;
+
+use common::{Found, ParseError, Solution};
+
+mod generate;
+
+fn all_distinct(g: &[u8]) -> bool {
+	g.len() == HashSet::<&u8>::from_iter(g).len()
+}
+
+// Where the first `u` distinct characters in a row end, if anywhere
+fn find_marker(u: usize, s: &str) -> Found<usize> {
+	Found(s.as_bytes()
+		.windows(u)
+		.enumerate()
+		.find(|(_, g)| all_distinct(g))
+		.map(|(i, g)| i + g.len()))
+}
+
+pub struct Day6;
+
+impl Solution for Day6 {
+	type Input = String;
+	type Answer1 = Found<usize>;
+	type Answer2 = Found<usize>;
+
+	fn parse(inp: &str) -> Result<Self::Input, ParseError> {
+		Ok(inp.to_string())
+	}
+
+	fn part1(inp: &Self::Input) -> Found<usize> {
+		find_marker(4, inp)
+	}
+
+	fn part2(inp: &Self::Input) -> Found<usize> {
+		find_marker(14, inp)
+	}
+}
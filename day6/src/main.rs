@@ -1,28 +1,20 @@
-use std::fs;
-use std::io;
-use std::collections::HashSet;
+use std::error::Error;
 
-fn all_distinct(g: &[u8]) -> bool {
-	g.len() == HashSet::<&u8>::from_iter(g).len()
-}
-
-fn find_marker(u: usize, s: &str) -> usize {
-	s.as_bytes()
-		.windows(u)
-		.enumerate()
-		.find(|(_, g)| all_distinct(g))
-		.map(|(i, g)| i + g.len())
-		.unwrap()
-}
+use common::Solution;
+use day6::Day6;
 
-fn main() -> io::Result<()> {
-	let inp = fs::read_to_string("./input.txt")?;
+fn main() -> Result<(), Box<dyn Error>> {
+	let inp = common::input::read_or_exit();
+	let mut inp = common::parse_or_exit::<Day6>(&inp);
+	common::configure_or_exit::<Day6>(6, &mut inp);
 
-	let part1 = find_marker(4, &inp);
-	println!("Part 1: {}", part1);
+	if common::json_requested() {
+		common::print_json::<Day6>(6, &inp);
+		return Ok(());
+	}
 
-	let part2 = find_marker(14, &inp);
-	println!("Part 2: {}", part2);
+	println!("Part 1: {}", Day6::part1(&inp));
+	println!("Part 2: {}", Day6::part2(&inp));
 
 	Ok(())
 }
@@ -0,0 +1,39 @@
+use common::{Found, Solution};
+use day6::Day6;
+
+const EXAMPLE: &str = include_str!("../example.txt");
+
+#[test]
+fn part1() {
+	let input = Day6::parse(EXAMPLE).unwrap();
+	assert_eq!(Day6::part1(&input), Found(Some(7)));
+}
+
+#[test]
+fn part2() {
+	let input = Day6::parse(EXAMPLE).unwrap();
+	assert_eq!(Day6::part2(&input), Found(Some(19)));
+}
+
+#[test]
+fn more_examples() {
+	let cases = [
+		("bvwbjplbgvbhsrlpgdmjqwftvncz", 5, 23),
+		("nppdvjthqldpwncqszvftbrmjlhg", 6, 23),
+		("nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg", 10, 29),
+		("zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw", 11, 26),
+	];
+	for (inp, p1, p2) in cases {
+		let input = Day6::parse(inp).unwrap();
+		assert_eq!(Day6::part1(&input), Found(Some(p1)), "{}", inp);
+		assert_eq!(Day6::part2(&input), Found(Some(p2)), "{}", inp);
+	}
+}
+
+#[test]
+fn no_marker() {
+	let input = Day6::parse("abababababababab\n").unwrap();
+	assert_eq!(Day6::part1(&input), Found(None));
+	let input = Day6::parse("").unwrap();
+	assert_eq!(Day6::part2(&input), Found(None));
+}
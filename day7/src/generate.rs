@@ -0,0 +1,75 @@
+use std::collections::HashSet;
+
+use common::{Generate, Rng};
+
+use crate::{Day7, NEED_UNUSED_SPACE, TOTAL_SPACE};
+
+const LETTERS: &[u8] = b"abcdefghijklmnopqrstuvwxyz";
+
+#[derive(Default)]
+struct Dir {
+	name: String,
+	names: HashSet<String>,
+	dirs: Vec<usize>,
+	files: Vec<(String, usize)>,
+}
+
+// A name not yet used in the directory
+fn new_name(rng: &mut Rng, dir: &mut Dir, ext: bool) -> String {
+	loop {
+		let len = rng.range(1..=8) as usize;
+		let mut name = rng.string(LETTERS, len);
+		if ext && rng.chance(0.5) {
+			name = format!("{}.{}", name, rng.string(LETTERS, 3));
+		}
+		if dir.names.insert(name.clone()) {
+			return name;
+		}
+	}
+}
+
+fn emit(dirs: &[Dir], idx: usize, out: &mut String) {
+	let dir = &dirs[idx];
+	*out += &format!("$ cd {}\n$ ls\n", dir.name);
+	for sub in dir.dirs.iter() {
+		*out += &format!("dir {}\n", dirs[*sub].name);
+	}
+	for (name, size) in dir.files.iter() {
+		*out += &format!("{} {}\n", size, name);
+	}
+	for sub in dir.dirs.iter() {
+		emit(dirs, *sub, out);
+		*out += "$ cd ..\n";
+	}
+}
+
+impl Generate for Day7 {
+	// The disk usage is kept between the space that must be freed and the
+	// total, so that part 2 always has an answer
+	fn generate(rng: &mut Rng, scale: usize) -> String {
+		let mut dirs = vec![Dir { name: "/".to_string(), ..Dir::default() }];
+		for _ in 0..180 * scale {
+			let parent = rng.below(dirs.len());
+			let name = new_name(rng, &mut dirs[parent], false);
+			let idx = dirs.len();
+			dirs[parent].dirs.push(idx);
+			dirs.push(Dir { name, ..Dir::default() });
+		}
+
+		let weights: Vec<_> = (0..300 * scale)
+			.map(|_| rng.range(1..=1000) as usize)
+			.collect();
+		let (total, need) = (TOTAL_SPACE as i64, NEED_UNUSED_SPACE as i64);
+		let total = rng.range(total - need + 1_000_000..=total - 1_000_000);
+		let sum = weights.iter().sum::<usize>();
+		for w in weights {
+			let dir = rng.below(dirs.len());
+			let name = new_name(rng, &mut dirs[dir], true);
+			dirs[dir].files.push((name, (w * total as usize / sum).max(1)));
+		}
+
+		let mut out = String::new();
+		emit(&dirs, 0, &mut out);
+		out
+	}
+}
@@ -0,0 +1,287 @@
+use std::iter;
+use std::str;
+use std::collections::HashMap;
+
+use common::{Error, Json, ParseError, Params, Scanner, Solution, ToJson};
+
+mod generate;
+
+// The puzzle's disk and update sizes, unless configured otherwise
+const TOTAL_SPACE: usize = 70000000;
+const NEED_UNUSED_SPACE: usize = 30000000;
+
+
+#[derive(Debug)]
+pub enum Node {
+	File(String, usize),
+	Dir(String, HashMap<String, Node>)
+}
+
+impl Node {
+	fn new_file(name: &str, sz: usize) -> Self {
+		Self::File(name.to_string(), sz)
+	}
+
+	fn new_dir(name: &str) -> Self {
+		Self::Dir(name.to_string(), HashMap::new())
+	}
+
+	pub fn name(&self) -> &str {
+		match self {
+			Self::File(name, _) |
+			Self::Dir(name, _) => name,
+		}
+	}
+
+	pub fn size(&self) -> usize {
+		match self {
+			Self::File(_, sz) => *sz,
+			Self::Dir(_, h) => h.values()
+				.map(|d| d.size()).sum(),
+		}
+	}
+
+	pub fn is_dir(&self) -> bool {
+		matches!(self, Self::Dir(_, _))
+	}
+
+	fn children_dir(&self) -> impl Iterator<Item=&Node> {
+		match self {
+			Self::Dir(_, h) => h.values().filter(|c| c.is_dir()),
+			_ => unreachable!(),
+		}
+	}
+
+	// Finds the node at the specified path
+	fn get_path(&self, path: &[&str]) -> Option<&Node> {
+		match (self, path) {
+			(_, [name]) if *name == self.name() => Some(self),
+			(Self::Dir(name, h), [first, rest @ ..]) if first == name =>
+				h.get(rest[0])?.get_path(rest),
+			_ => None,
+		}
+	}
+
+	// Adds a node at the specified path
+	fn add_subnode_path(&mut self, path: &[&str], node: Node) {
+		match self {
+			Self::Dir(name, h) => {
+				assert!(path[0] == name);
+				match path.len() {
+					1 => { h.insert(node.name().to_string(), node); },
+					_ => { h.get_mut(path[1])
+						.unwrap().add_subnode_path(&path[1..], node); },
+				}
+			},
+			_ => unreachable!(),
+		}
+	}
+
+	fn part1(&self) -> usize {
+		self.children_dir()
+			.map(|ch| {
+				let size = ch.size();
+				ch.part1() + if size <= 100000 { size } else { 0 }
+			})
+			.sum()
+	}
+
+	fn part2(&self, needed: usize) -> Option<usize> {
+		self.children_dir()
+			.filter_map(|c| c.part2(needed))
+			.chain(iter::once(self.size()))
+			.filter(|sz| *sz >= needed)
+			.min()
+	}
+}
+
+// Directories list their children by name, so that the output is the same
+// on every run
+impl ToJson for Node {
+	fn to_json(&self) -> Json {
+		match self {
+			Self::File(name, sz) => Json::object([
+				("name", name.to_json()),
+				("size", sz.to_json()),
+			]),
+			Self::Dir(name, h) => {
+				let mut children: Vec<_> = h.values().collect();
+				children.sort_by_key(|c| c.name());
+				Json::object([
+					("name", name.to_json()),
+					("children", Json::Array(children.into_iter()
+						.map(Node::to_json)
+						.collect())),
+				])
+			}
+		}
+	}
+}
+
+#[derive(Debug)]
+pub enum Command<'a> {
+	Cd(&'a str),
+	Ls(Vec<Node>),
+}
+
+type Lines<'a> = &'a [(usize, &'a str)];
+
+impl<'a> Command<'a> {
+	fn parse_node(ln: &str) -> Result<Node, ParseError> {
+		let mut sc = Scanner::new(ln);
+		let node = match sc.rest().starts_with("dir ") {
+			true => {
+				sc.tag("dir ")?;
+				Node::new_dir(sc.word()?)
+			}
+			false => {
+				let size = sc.number::<usize>()?;
+				sc.tag(" ")?;
+				Node::new_file(sc.word()?, size)
+			}
+		};
+		sc.end()?;
+		Ok(node)
+	}
+
+	fn parse_command(inp: Lines<'a>) -> Result<(Self, Lines<'a>), ParseError> {
+		let (lineno, line) = inp[0];
+		let mut sc = Scanner::new(line);
+		sc.tag("$ ").map_err(|e| e.at_line(lineno))?;
+
+		let (linenum, cmd) = match sc.word().map_err(|e| e.at_line(lineno))? {
+			"cd" => {
+				let name = sc.tag(" ")
+					.and_then(|_| sc.word())
+					.map_err(|e| e.at_line(lineno))?;
+				(1, Command::Cd(name))
+			}
+			"ls" => {
+				let nodes = inp[1..].iter()
+					.take_while(|(_, ln)| !ln.starts_with('$'))
+					.map(|(i, ln)| Self::parse_node(ln)
+						.map_err(|e| e.at_line(*i)))
+					.collect::<Result<Vec<_>, _>>()?;
+				(nodes.len() + 1, Command::Ls(nodes))
+			}
+			cmd => return Err(ParseError::new(2, cmd, "`cd` or `ls`")
+				.at_line(lineno)),
+		};
+		sc.end().map_err(|e| e.at_line(lineno))?;
+
+		Ok((cmd, &inp[linenum..]))
+	}
+}
+
+pub struct Commands<'a>(Vec<(usize, Command<'a>)>);
+
+impl<'a> TryFrom<Lines<'a>> for Commands<'a> {
+	type Error = ParseError;
+	fn try_from(mut lines: Lines<'a>) -> Result<Self, Self::Error> {
+		let mut cmds = Vec::new();
+		while !lines.is_empty() {
+			let lineno = lines[0].0;
+			let res = Command::parse_command(lines)?;
+			cmds.push((lineno, res.0));
+			lines = res.1;
+		}
+		Ok(Self(cmds))
+	}
+}
+
+impl<'a> Commands<'a> {
+	fn get_tree(self) -> Result<Node, ParseError> {
+		let mut root = Node::new_dir("/");
+		let mut dirstack = Vec::with_capacity(10);
+		dirstack.push("/");
+
+		for (lineno, cmd) in self.0.into_iter() {
+			let err = |name, expected| ParseError::new(5, name, expected)
+				.at_line(lineno);
+			match cmd {
+				Command::Cd(name) => match name {
+					"/" => {
+						dirstack.clear();
+						dirstack.push("/");
+					},
+					".." if dirstack.len() == 1 =>
+						return Err(err(name, "a directory other than `..` at `/`")),
+					".." => {
+						dirstack.pop();
+					},
+					d => {
+						dirstack.push(d);
+						if !root.get_path(&dirstack).is_some_and(Node::is_dir) {
+							return Err(err(d, "a listed directory"));
+						}
+					}
+				},
+				Command::Ls(files) => {
+					for file in files.into_iter() {
+						root.add_subnode_path(&dirstack, file);
+					}
+				}
+			}
+		}
+
+		Ok(root)
+	}
+}
+
+pub struct Filesystem {
+	pub root: Node,
+	pub total_space: usize,
+	// Unused space needed for the update in part 2
+	pub need_unused_space: usize,
+}
+
+impl ToJson for Filesystem {
+	fn to_json(&self) -> Json {
+		Json::object([
+			("root", self.root.to_json()),
+			("total_space", self.total_space.to_json()),
+			("need_unused_space", self.need_unused_space.to_json()),
+		])
+	}
+}
+
+pub struct Day7;
+
+impl Solution for Day7 {
+	type Input = Filesystem;
+	type Answer1 = usize;
+	type Answer2 = usize;
+
+	fn parse(inp: &str) -> Result<Self::Input, ParseError> {
+		let lines = common::lines(inp).collect::<Vec<_>>();
+		let root = Commands::try_from(&lines[..])?.get_tree()?;
+		Ok(Filesystem {
+			root,
+			total_space: TOTAL_SPACE,
+			need_unused_space: NEED_UNUSED_SPACE,
+		})
+	}
+
+	fn part1(fs: &Self::Input) -> usize {
+		fs.root.part1()
+	}
+
+	fn part2(fs: &Self::Input) -> usize {
+		let unused_space = fs.total_space.saturating_sub(fs.root.size());
+		let must_free = fs.need_unused_space.saturating_sub(unused_space);
+		// The root directory always frees enough, as configured
+		fs.root.part2(must_free).unwrap()
+	}
+
+	fn configure(fs: &mut Self::Input, params: &Params) -> Result<(), Error> {
+		params.expect(&["total_space", "need_unused_space"])?;
+		params.apply("total_space", 0, &mut fs.total_space)?;
+		params.apply("need_unused_space", 0, &mut fs.need_unused_space)?;
+		// Otherwise even deleting everything wouldn't free enough space
+		match fs.need_unused_space <= fs.total_space {
+			true => Ok(()),
+			false => Err(format!("need_unused_space must be at most total_space ({})",
+				fs.total_space).into()),
+		}
+	}
+}
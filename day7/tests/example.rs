@@ -0,0 +1,16 @@
+use common::Solution;
+use day7::Day7;
+
+const EXAMPLE: &str = include_str!("../example.txt");
+
+#[test]
+fn part1() {
+	let input = Day7::parse(EXAMPLE).unwrap();
+	assert_eq!(Day7::part1(&input), 95437);
+}
+
+#[test]
+fn part2() {
+	let input = Day7::parse(EXAMPLE).unwrap();
+	assert_eq!(Day7::part2(&input), 24933642);
+}
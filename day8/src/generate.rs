@@ -0,0 +1,13 @@
+use common::{Generate, Rng};
+use common::rng::scale_side;
+
+use crate::Day8;
+
+impl Generate for Day8 {
+	fn generate(rng: &mut Rng, scale: usize) -> String {
+		let side = scale_side(99, scale);
+		(0..side)
+			.map(|_| rng.string(b"0123456789", side) + "\n")
+			.collect()
+	}
+}
@@ -0,0 +1,168 @@
+use std::str;
+
+use common::{Json, ParseError, Solution, ToJson};
+use grid::Grid;
+
+mod generate;
+
+pub type Tree = u8;
+
+#[derive(Debug)]
+pub struct Forest {
+	trees: Grid<Tree>
+}
+
+impl Forest {
+	pub fn width(&self) -> usize {
+		self.trees.width()
+	}
+
+	pub fn height(&self) -> usize {
+		self.trees.height()
+	}
+
+	fn tree_is_left_edge(&self, _row: usize, col: usize) -> bool {
+		col == 0
+	}
+
+	fn tree_is_right_edge(&self, _row: usize, col: usize) -> bool {
+		col == self.width() - 1
+	}
+
+	fn tree_is_up_edge(&self, row: usize, _col: usize) -> bool {
+		row == 0
+	}
+
+	fn tree_is_down_edge(&self, row: usize, _col: usize) -> bool {
+		row == self.height() - 1
+	}
+
+	fn tree_is_edge(&self, row: usize, col: usize) -> bool {
+		self.tree_is_left_edge(row, col) ||
+			self.tree_is_up_edge(row, col) ||
+			self.tree_is_down_edge(row, col) ||
+			self.tree_is_right_edge(row, col)			
+	}
+
+	fn column(&self, col: usize) -> impl Iterator<Item=Tree> + '_ {
+		self.trees.column(col).copied()
+	}
+
+	pub fn tree(&self, row: usize, col: usize) -> Tree {
+		self.trees[(row, col)]
+	}
+
+	pub fn tree_visible(&self, row: usize, col: usize) -> bool{
+		if self.tree_is_edge(row, col) {
+			return true;
+		}
+
+		let tree = self.tree(row, col);
+		let rowv = self.trees.row(row);
+
+		if rowv[..col].iter().all(|t| *t < tree) {
+			return true;
+		}
+
+		if rowv[col + 1..].iter().all(|t| *t < tree) {
+			return true;
+		}
+
+		let column = self.column(col).collect::<Vec<_>>();
+
+		if column[..row].iter().all(|t| *t < tree) {
+			return true;
+		}
+
+		if column[row + 1..].iter().all(|t| *t < tree) {
+			return true;
+		}
+
+		false
+	}
+
+	pub fn tree_score(&self, row: usize, col: usize) -> usize {
+		let tree = self.tree(row, col);
+		let rowv = self.trees.row(row);
+
+		let mut left = rowv[..col].iter()
+			.rev()
+			.take_while(|t| **t < tree)
+			.count();
+		left += !self.tree_is_left_edge(row, col - left) as usize;
+
+		let mut right = rowv[col + 1..].iter()
+			.take_while(|t| **t < tree)
+			.count();
+		right += !self.tree_is_right_edge(row, col + right) as usize;
+
+		let column = self.column(col).collect::<Vec<_>>();
+
+		let mut up = column[..row].iter()
+			.rev()
+			.take_while(|t| **t < tree)
+			.count();
+		up += !self.tree_is_up_edge(row - up, col) as usize;
+
+		let mut down = column[row + 1..].iter()
+			.take_while(|t| **t < tree)
+			.count();
+		down += !self.tree_is_down_edge(row + down, col) as usize;
+
+		left * right * down * up
+
+	}
+
+	fn part1(&self) -> usize {
+		self.trees.positions()
+			.filter(|(i, j)| self.tree_visible(*i, *j))
+			.count()
+	}
+
+	// There is always a tree, as parsing makes sure
+	fn part2(&self) -> usize {
+		self.trees.positions()
+			.map(|(i, j)| self.tree_score(i, j))
+			.max().unwrap()
+	}
+}
+
+// The tree heights, row by row
+impl ToJson for Forest {
+	fn to_json(&self) -> Json {
+		self.trees.to_json()
+	}
+}
+
+impl str::FromStr for Forest {
+	type Err = ParseError;
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		let trees = Grid::parse(s, |c| c.to_digit(10)
+			.map(|d| d as Tree)
+			.ok_or("a tree height digit"))?;
+		if trees.height() == 0 {
+			return Err(ParseError::new(0, "", "a row of trees"));
+		}
+		Ok(Self { trees })
+	}
+}
+
+pub struct Day8;
+
+impl Solution for Day8 {
+	type Input = Forest;
+	type Answer1 = usize;
+	type Answer2 = usize;
+
+	fn parse(inp: &str) -> Result<Self::Input, ParseError> {
+		inp.parse::<Forest>()
+	}
+
+	fn part1(forest: &Self::Input) -> usize {
+		forest.part1()
+	}
+
+	fn part2(forest: &Self::Input) -> usize {
+		forest.part2()
+	}
+}
@@ -0,0 +1,23 @@
+use common::Solution;
+use day8::Day8;
+
+const EXAMPLE: &str = include_str!("../example.txt");
+
+#[test]
+fn part1() {
+	let input = Day8::parse(EXAMPLE).unwrap();
+	assert_eq!(Day8::part1(&input), 21);
+}
+
+#[test]
+fn part2() {
+	let input = Day8::parse(EXAMPLE).unwrap();
+	assert_eq!(Day8::part2(&input), 8);
+}
+
+#[test]
+fn no_trees() {
+	assert!(Day8::parse("").is_err());
+	let input = Day8::parse("5\n").unwrap();
+	assert_eq!(Day8::part2(&input), 0);
+}
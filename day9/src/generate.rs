@@ -0,0 +1,13 @@
+use common::{Generate, Rng};
+
+use crate::Day9;
+
+impl Generate for Day9 {
+	fn generate(rng: &mut Rng, scale: usize) -> String {
+		(0..2000 * scale)
+			.map(|_| format!("{} {}\n",
+				rng.choose(&['U', 'D', 'L', 'R']),
+				rng.range(1..=19)))
+			.collect()
+	}
+}
@@ -0,0 +1,106 @@
+use std::collections::HashSet;
+use std::iter;
+
+use common::{Error, Json, ParseError, Params, Scanner, Solution, ToJson};
+use geometry::{Direction4, Point};
+
+mod generate;
+mod replay;
+
+// Parses a motion of the head as a direction and how many times it repeats,
+// which is only expanded while simulating
+fn parse_motion(s: &str) -> Result<(Direction4, usize), ParseError> {
+	let mut sc = Scanner::new(s);
+	let dir = match sc.word()? {
+		"R" => Direction4::Right,
+		"L" => Direction4::Left,
+		"U" => Direction4::Up,
+		"D" => Direction4::Down,
+		dir => return Err(ParseError::new(0, dir, "R, L, U or D")),
+	};
+	sc.tag(" ")?;
+	let amount = sc.number::<usize>()?;
+	sc.end()?;
+	Ok((dir, amount))
+}
+
+pub type Knot = Point<i64>;
+
+// Simulates a rope of `len` knots, calling `on_step` with the knots after
+// each movement of the head
+fn simulate(movs: &[(Direction4, usize)], len: usize,
+	mut on_step: impl FnMut(&[Knot])) -> usize
+{
+	let mut knots = vec![Knot::default(); len];
+	let mut visited = HashSet::new();
+
+	let movs = movs.iter().flat_map(|&(dir, n)| iter::repeat_n(dir, n));
+	for dir in movs {
+		knots[0] = knots[0].step(dir);
+		// A knot no longer touching the one ahead moves one step towards
+		// it, diagonally if they aren't in line
+		for i in 1..len {
+			if knots[i].chebyshev(knots[i - 1]) > 1 {
+				let dir = knots[i].direction_to(knots[i - 1]).unwrap();
+				knots[i] = knots[i].step(dir);
+			}
+		}
+		visited.insert(knots[len - 1]);
+		on_step(&knots);
+	}
+
+	visited.len()
+}
+
+pub struct Motions {
+	pub movs: Vec<(Direction4, usize)>,
+	// Length of the rope in part 2
+	pub knots: usize,
+}
+
+// Motions are written as in the input, such as `R 4`
+impl ToJson for Motions {
+	fn to_json(&self) -> Json {
+		let movs = self.movs.iter()
+			.map(|&(dir, n)| {
+				let dir = match dir {
+					Direction4::Right => "R",
+					Direction4::Left => "L",
+					Direction4::Up => "U",
+					Direction4::Down => "D",
+				};
+				format!("{} {}", dir, n).to_json()
+			})
+			.collect();
+		Json::object([
+			("movs", Json::Array(movs)),
+			("knots", self.knots.to_json()),
+		])
+	}
+}
+
+pub struct Day9;
+
+impl Solution for Day9 {
+	type Input = Motions;
+	type Answer1 = usize;
+	type Answer2 = usize;
+
+	fn parse(inp: &str) -> Result<Self::Input, ParseError> {
+		common::parse_lines(inp, parse_motion)
+			.map(|movs| Motions { movs, knots: 10 })
+	}
+
+	fn part1(motions: &Self::Input) -> usize {
+		simulate(&motions.movs, 2, |_| ())
+	}
+
+	fn part2(motions: &Self::Input) -> usize {
+		simulate(&motions.movs, motions.knots, |_| ())
+	}
+
+	fn configure(motions: &mut Self::Input, params: &Params) -> Result<(), Error> {
+		params.expect(&["knots"])?;
+		params.apply("knots", 1, &mut motions.knots)
+	}
+}
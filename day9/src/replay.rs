@@ -0,0 +1,67 @@
+use std::collections::HashMap;
+
+use visual::{Simulation, Visualize};
+
+use crate::{Day9, Knot, Motions, simulate};
+
+// The visible part of the rope's surroundings, centered on the head
+const ROWS: i64 = 21;
+const COLS: i64 = 61;
+
+// The rope after each movement of its head
+struct Replay {
+	knots: Vec<Vec<Knot>>,
+	// The step at which the tail first visited each position
+	visited: HashMap<Knot, usize>,
+}
+
+impl Replay {
+	fn new(motions: &Motions, part: u8) -> Self {
+		let len = if part == 1 { 2 } else { motions.knots };
+		let mut knots = vec![vec![Knot::default(); len]];
+		simulate(&motions.movs, len, |rope| knots.push(rope.to_vec()));
+
+		let mut visited = HashMap::new();
+		for (step, rope) in knots.iter().enumerate() {
+			visited.entry(rope[len - 1]).or_insert(step);
+		}
+		Self { knots, visited }
+	}
+}
+
+impl Simulation for Replay {
+	fn steps(&self) -> usize {
+		self.knots.len() - 1
+	}
+
+	fn render(&self, step: usize) -> String {
+		let rope = &self.knots[step];
+		let head = rope[0];
+		let mut out = String::new();
+		for y in head.y - ROWS / 2..=head.y + ROWS / 2 {
+			for x in head.x - COLS / 2..=head.x + COLS / 2 {
+				let p = Knot::new(x, y);
+				let knot = rope.iter().position(|&k| k == p);
+				out.push(match knot {
+					Some(0) => 'H',
+					Some(_) if rope.len() == 2 => 'T',
+					Some(i) => char::from_digit(i as u32, 36).unwrap_or('*'),
+					None if p == Knot::default() => 's',
+					None if self.visited.get(&p)
+						.is_some_and(|&s| s <= step) => '#',
+					None => '.',
+				});
+			}
+			out.push('\n');
+		}
+		let count = self.visited.values().filter(|&&s| s <= step).count();
+		out.push_str(&format!("\ntail visited {} positions", count));
+		out
+	}
+}
+
+impl Visualize for Day9 {
+	fn simulation(motions: &Self::Input, part: u8) -> Box<dyn Simulation> {
+		Box::new(Replay::new(motions, part))
+	}
+}
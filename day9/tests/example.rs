@@ -0,0 +1,23 @@
+use common::Solution;
+use day9::Day9;
+
+const EXAMPLE: &str = include_str!("../example.txt");
+
+#[test]
+fn part1() {
+	let input = Day9::parse(EXAMPLE).unwrap();
+	assert_eq!(Day9::part1(&input), 13);
+}
+
+#[test]
+fn part2() {
+	let input = Day9::parse(EXAMPLE).unwrap();
+	assert_eq!(Day9::part2(&input), 1);
+}
+
+// Part 2 has a larger example where the tail actually moves
+#[test]
+fn part2_larger() {
+	let input = Day9::parse(include_str!("../example2.txt")).unwrap();
+	assert_eq!(Day9::part2(&input), 36);
+}
@@ -0,0 +1,128 @@
+use std::fmt;
+use std::fs;
+use std::io;
+use std::panic;
+use std::path::{Path, PathBuf};
+
+use common::{Rng, Solution, panic_message};
+
+mod mutate;
+
+pub use mutate::{arbitrary, mutate};
+
+pub const NUM_DAYS: u8 = 15;
+
+/// An input that made a parser panic, rather than return an error.
+#[derive(Debug, Clone)]
+pub struct Crash {
+	pub day: u8,
+	pub input: String,
+	pub message: String,
+}
+
+impl fmt::Display for Crash {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		write!(f, "day {} parser panicked: {}\ninput: {:?}",
+			self.day, self.message, self.input)
+	}
+}
+
+fn parse<S: Solution>(inp: &str) -> Result<(), String> {
+	panic::catch_unwind(|| { let _ = S::parse(inp); })
+		.map_err(|payload| panic_message(&*payload))
+}
+
+/// Runs the parser of the given day on `inp`, returning the panic message
+/// if it panics. Parse errors are fine.
+pub fn check(day: u8, inp: &str) -> Result<(), String> {
+	match day {
+		1 => parse::<day1::Day1>(inp),
+		2 => parse::<day2::Day2>(inp),
+		3 => parse::<day3::Day3>(inp),
+		4 => parse::<day4::Day4>(inp),
+		5 => parse::<day5::Day5>(inp),
+		6 => parse::<day6::Day6>(inp),
+		7 => parse::<day7::Day7>(inp),
+		8 => parse::<day8::Day8>(inp),
+		9 => parse::<day9::Day9>(inp),
+		10 => parse::<day10::Day10>(inp),
+		11 => parse::<day11::Day11>(inp),
+		12 => parse::<day12::Day12>(inp),
+		13 => parse::<day13::Day13>(inp),
+		14 => parse::<day14::Day14>(inp),
+		15 => parse::<day15::Day15>(inp),
+		day => panic!("no such day: {}", day),
+	}
+}
+
+/// The corpus directory of a day, where crashing inputs are saved so that
+/// later runs check them again.
+pub fn corpus_dir(day: u8) -> PathBuf {
+	PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+		.join("corpus")
+		.join(format!("day{}", day))
+}
+
+// The crate of a day, whose examples seed its corpus so that they can't
+// drift apart from copies
+fn day_dir(day: u8) -> PathBuf {
+	PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+		.join("..")
+		.join(format!("day{}", day))
+}
+
+// The files in `dir` whose names pass `keep`, in a stable order
+fn read_files(dir: &Path, keep: impl Fn(&str) -> bool) -> io::Result<Vec<String>> {
+	let mut paths = fs::read_dir(dir)?
+		.map(|entry| entry.map(|e| e.path()))
+		.filter(|path| path.as_ref().map_or(true, |p| p.file_name()
+			.and_then(|n| n.to_str())
+			.is_some_and(&keep)))
+		.collect::<io::Result<Vec<_>>>()?;
+	paths.sort();
+	paths.iter().map(fs::read_to_string).collect()
+}
+
+/// Reads the examples of a day, followed by every input saved in its
+/// corpus. Days without any saved input have no corpus directory.
+pub fn load_corpus(day: u8) -> io::Result<Vec<String>> {
+	let mut corpus = read_files(&day_dir(day),
+		|name| name.starts_with("example") && name.ends_with(".txt"))?;
+	match read_files(&corpus_dir(day), |_| true) {
+		Ok(saved) => corpus.extend(saved),
+		Err(e) if e.kind() == io::ErrorKind::NotFound => (),
+		Err(e) => return Err(e),
+	}
+	Ok(corpus)
+}
+
+/// Checks the parser of a day on its whole corpus, then on `iters` inputs
+/// that are mostly mutated from it and sometimes arbitrary, stopping at the
+/// first crash.
+pub fn fuzz(day: u8, corpus: &[String], seed: u64, iters: usize) -> Result<(), Crash> {
+	let crash = |input: &str, message| Crash { day, input: input.to_string(), message };
+
+	// Panics are expected and reported, so the default hook's output would
+	// only get in the way
+	let hook = panic::take_hook();
+	panic::set_hook(Box::new(|_| ()));
+	let res = (|| {
+		for inp in corpus {
+			check(day, inp).map_err(|msg| crash(inp, msg))?;
+		}
+		if corpus.is_empty() {
+			return Ok(());
+		}
+		let mut rng = Rng::new(seed);
+		for _ in 0..iters {
+			let inp = match rng.chance(0.1) {
+				true => arbitrary(&mut rng),
+				false => mutate(&mut rng, corpus),
+			};
+			check(day, &inp).map_err(|msg| crash(&inp, msg))?;
+		}
+		Ok(())
+	})();
+	panic::set_hook(hook);
+	res
+}
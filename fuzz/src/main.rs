@@ -0,0 +1,89 @@
+use std::collections::hash_map::DefaultHasher;
+use std::env;
+use std::fs;
+use std::hash::{Hash, Hasher};
+use std::process;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use fuzz::NUM_DAYS;
+
+const USAGE: &str = "\
+Usage:
+    fuzz [<day>] [--iters N] [--seed N]";
+
+fn parse_num<T: std::str::FromStr>(what: &str, val: Option<String>) -> Result<T, String> {
+	let val = val.ok_or_else(|| format!("missing {}", what))?;
+	val.parse::<T>().map_err(|_| format!("invalid {}: {}", what, val))
+}
+
+fn parse_args(mut args: impl Iterator<Item = String>)
+	-> Result<(Option<u8>, usize, Option<u64>), String>
+{
+	let mut day = None;
+	let mut iters = 100000;
+	let mut seed = None;
+	while let Some(arg) = args.next() {
+		match arg.as_str() {
+			"--iters" => iters = parse_num("iteration count", args.next())?,
+			"--seed" => seed = Some(parse_num("seed", args.next())?),
+			_ if day.is_none() => day = Some(parse_num("day", Some(arg))?),
+			_ => return Err(format!("unexpected argument: {}", arg)),
+		}
+	}
+	if day.is_some_and(|d| !(1..=NUM_DAYS).contains(&d)) {
+		return Err(format!("no such day: {}", day.unwrap()));
+	}
+	Ok((day, iters, seed))
+}
+
+// Saves a crashing input to the corpus, named after its contents
+fn save_crash(crash: &fuzz::Crash) -> std::io::Result<String> {
+	let mut hasher = DefaultHasher::new();
+	crash.input.hash(&mut hasher);
+	let dir = fuzz::corpus_dir(crash.day);
+	fs::create_dir_all(&dir)?;
+	let path = dir.join(format!("crash-{:016x}.txt", hasher.finish()));
+	fs::write(&path, &crash.input)?;
+	Ok(path.display().to_string())
+}
+
+fn main() {
+	let (only, iters, seed) = parse_args(env::args().skip(1)).unwrap_or_else(|e| {
+		eprintln!("error: {}\n\n{}", e, USAGE);
+		process::exit(2);
+	});
+	let seed = seed.unwrap_or_else(|| SystemTime::now()
+		.duration_since(UNIX_EPOCH)
+		.map_or(0, |d| d.as_nanos() as u64));
+	eprintln!("seed {}", seed);
+
+	let days = match only {
+		Some(day) => day..=day,
+		None => 1..=NUM_DAYS,
+	};
+	let mut ok = true;
+	for day in days {
+		let corpus = match fuzz::load_corpus(day) {
+			Ok(corpus) => corpus,
+			Err(e) => {
+				eprintln!("Day {}: cannot read corpus: {}", day, e);
+				ok = false;
+				continue;
+			}
+		};
+		match fuzz::fuzz(day, &corpus, seed, iters) {
+			Ok(()) => println!("Day {}: ok after {} inputs", day, corpus.len() + iters),
+			Err(crash) => {
+				println!("Day {}: {}", day, crash);
+				match save_crash(&crash) {
+					Ok(path) => println!("Day {}: saved to {}", day, path),
+					Err(e) => eprintln!("Day {}: cannot save crash: {}", day, e),
+				}
+				ok = false;
+			}
+		}
+	}
+	if !ok {
+		process::exit(1);
+	}
+}
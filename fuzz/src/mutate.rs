@@ -0,0 +1,101 @@
+use common::Rng;
+
+// Characters that mean something to at least one of the parsers, along
+// with a few that none of them expect
+const INTERESTING: &[char] = &[
+	'0', '1', '9', 'a', 'z', 'A', 'Z', 'S', 'E', ' ', '\n', ',', '-', '+',
+	'[', ']', ':', '=', '$', '/', '.', '#', '>', '\t', '\0', 'é', '∞',
+];
+
+// Numbers at or past the edges of the types the parsers read into
+const NUMBERS: &[&str] = &[
+	"0", "-1", "-0", "255", "256", "4294967296", "9223372036854775807",
+	"-9223372036854775808", "18446744073709551616", "",
+];
+
+fn random_char(rng: &mut Rng, chars: &[char]) -> char {
+	match rng.chance(0.5) && !chars.is_empty() {
+		true => *rng.choose(chars),
+		false => *rng.choose(INTERESTING),
+	}
+}
+
+// A random range of `len` items, possibly empty
+fn span(rng: &mut Rng, len: usize) -> (usize, usize) {
+	let start = rng.below(len + 1);
+	let end = start + rng.below(len - start + 1).min(16);
+	(start, end)
+}
+
+// Replaces one of the numbers in the input with an extreme one
+fn replace_number(rng: &mut Rng, chars: &mut Vec<char>) {
+	let starts: Vec<_> = (0..chars.len())
+		.filter(|&i| chars[i].is_ascii_digit()
+			&& (i == 0 || !chars[i - 1].is_ascii_digit()))
+		.collect();
+	if starts.is_empty() {
+		return;
+	}
+	let start = *rng.choose(&starts);
+	let end = (start..chars.len())
+		.find(|&i| !chars[i].is_ascii_digit())
+		.unwrap_or(chars.len());
+	chars.splice(start..end, rng.choose(NUMBERS).chars());
+}
+
+fn mutate_lines(rng: &mut Rng, chars: &mut Vec<char>) {
+	let text: String = chars.iter().collect();
+	let mut lines: Vec<&str> = text.split('\n').collect();
+	let i = rng.below(lines.len());
+	let j = rng.below(lines.len());
+	match rng.below(3) {
+		0 => lines.swap(i, j),
+		1 => { lines.remove(i); }
+		_ => lines.insert(i, lines[j]),
+	}
+	*chars = lines.join("\n").chars().collect();
+}
+
+/// An input made up from scratch, of characters the parsers care about.
+pub fn arbitrary(rng: &mut Rng) -> String {
+	let len = rng.below(256);
+	(0..len).map(|_| *rng.choose(INTERESTING)).collect()
+}
+
+/// Derives a new input from a random entry of the corpus, with a few random
+/// edits: inserting, deleting or repeating text, swapping lines, splicing
+/// in part of another entry, and swapping numbers for extreme ones.
+pub fn mutate(rng: &mut Rng, corpus: &[String]) -> String {
+	let mut chars: Vec<char> = rng.choose(corpus).chars().collect();
+	let alphabet = chars.clone();
+
+	for _ in 0..rng.range(1..=4) {
+		let (start, end) = span(rng, chars.len());
+		match rng.below(10) {
+			0 => { chars.drain(start..end); }
+			1 => chars.insert(start, random_char(rng, &alphabet)),
+			2 if start < chars.len() => chars[start] = random_char(rng, &alphabet),
+			3 => {
+				let dup: Vec<_> = chars[start..end].to_vec();
+				chars.splice(end..end, dup);
+			}
+			// Long repeats find deep nesting and huge lines
+			4 if rng.chance(0.1) => {
+				let times = rng.range(2..=2000) as usize;
+				let dup = chars[start..end].repeat(times);
+				chars.splice(end..end, dup);
+			}
+			4 | 8 => mutate_lines(rng, &mut chars),
+			5 | 6 => replace_number(rng, &mut chars),
+			7 => {
+				let other: Vec<char> = rng.choose(corpus).chars().collect();
+				let from = rng.below(other.len() + 1);
+				chars.truncate(start);
+				chars.extend(&other[from..]);
+			}
+			_ => chars.truncate(start),
+		}
+	}
+
+	chars.into_iter().collect()
+}
@@ -0,0 +1,11 @@
+// A short fuzzing run over every parser, with a fixed seed so that failures
+// reproduce. Longer runs go through the `fuzz` binary.
+#[test]
+fn parsers_do_not_panic() {
+	for day in 1..=fuzz::NUM_DAYS {
+		let corpus = fuzz::load_corpus(day).unwrap();
+		if let Err(crash) = fuzz::fuzz(day, &corpus, day as u64, 2000) {
+			panic!("{}", crash);
+		}
+	}
+}
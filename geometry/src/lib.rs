@@ -0,0 +1,251 @@
+use std::cmp::Ordering;
+use std::fmt;
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+
+use common::{Json, ToJson};
+
+/// An integer type usable as a coordinate.
+pub trait Coord: Copy + Ord + fmt::Debug {
+	/// The distance between two values along an axis.
+	fn abs_diff(self, other: Self) -> u64;
+
+	/// Adds a small signed offset, or returns `None` on overflow.
+	fn checked_offset(self, delta: i8) -> Option<Self>;
+}
+
+macro_rules! impl_coord {
+	($add:ident: $($t:ty)*) => {$(
+		impl Coord for $t {
+			fn abs_diff(self, other: Self) -> u64 {
+				<$t>::abs_diff(self, other) as u64
+			}
+
+			fn checked_offset(self, delta: i8) -> Option<Self> {
+				self.$add(delta.into())
+			}
+		}
+	)*}
+}
+
+impl_coord!(checked_add: i8 i16 i32 i64 isize);
+impl_coord!(checked_add_signed: u8 u16 u32 u64 usize);
+
+/// One of the four orthogonal directions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Direction4 {
+	Up,
+	Right,
+	Down,
+	Left,
+}
+
+impl Direction4 {
+	/// All directions, clockwise from `Up`.
+	pub const ALL: [Self; 4] = [Self::Up, Self::Right, Self::Down, Self::Left];
+
+	/// The `(x, y)` offset of a step in this direction.
+	pub fn delta(self) -> (i8, i8) {
+		Direction8::from(self).delta()
+	}
+}
+
+/// One of the four orthogonal or four diagonal directions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Direction8 {
+	Up,
+	UpRight,
+	Right,
+	DownRight,
+	Down,
+	DownLeft,
+	Left,
+	UpLeft,
+}
+
+impl Direction8 {
+	/// All directions, clockwise from `Up`.
+	pub const ALL: [Self; 8] = [
+		Self::Up, Self::UpRight, Self::Right, Self::DownRight,
+		Self::Down, Self::DownLeft, Self::Left, Self::UpLeft,
+	];
+
+	/// The `(x, y)` offset of a step in this direction.
+	pub fn delta(self) -> (i8, i8) {
+		match self {
+			Self::Up        => ( 0, -1),
+			Self::UpRight   => ( 1, -1),
+			Self::Right     => ( 1,  0),
+			Self::DownRight => ( 1,  1),
+			Self::Down      => ( 0,  1),
+			Self::DownLeft  => (-1,  1),
+			Self::Left      => (-1,  0),
+			Self::UpLeft    => (-1, -1),
+		}
+	}
+
+	fn from_delta(delta: (i8, i8)) -> Option<Self> {
+		Self::ALL.into_iter().find(|d| d.delta() == delta)
+	}
+}
+
+impl From<Direction4> for Direction8 {
+	fn from(dir: Direction4) -> Self {
+		match dir {
+			Direction4::Up    => Self::Up,
+			Direction4::Right => Self::Right,
+			Direction4::Down  => Self::Down,
+			Direction4::Left  => Self::Left,
+		}
+	}
+}
+
+/// A point on the plane, or the vector between two points. `y` grows
+/// downwards, like rows on a screen, so `Up` decreases it.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Point<T> {
+	pub x: T,
+	pub y: T,
+}
+
+impl<T> Point<T> {
+	pub const fn new(x: T, y: T) -> Self {
+		Self { x, y }
+	}
+}
+
+impl<T: Coord> Point<T> {
+	pub fn manhattan(self, other: Self) -> u64 {
+		self.x.abs_diff(other.x) + self.y.abs_diff(other.y)
+	}
+
+	/// The distance when diagonal steps are allowed, so that all eight
+	/// neighbours of a point are at distance 1.
+	pub fn chebyshev(self, other: Self) -> u64 {
+		self.x.abs_diff(other.x).max(self.y.abs_diff(other.y))
+	}
+
+	/// The point one step away in `dir`, or `None` if it doesn't fit in `T`.
+	pub fn checked_step(self, dir: impl Into<Direction8>) -> Option<Self> {
+		let (dx, dy) = dir.into().delta();
+		Some(Self::new(self.x.checked_offset(dx)?, self.y.checked_offset(dy)?))
+	}
+
+	/// The point one step away in `dir`.
+	///
+	/// # Panics
+	///
+	/// If the point doesn't fit in `T`.
+	pub fn step(self, dir: impl Into<Direction8>) -> Self {
+		self.checked_step(dir).expect("point out of range")
+	}
+
+	/// The point one step away in `dir`, if it lies within `bounds`.
+	pub fn step_within(self, dir: impl Into<Direction8>, bounds: Bounds<T>)
+		-> Option<Self>
+	{
+		self.checked_step(dir).filter(|p| bounds.contains(*p))
+	}
+
+	/// The up to 4 orthogonally adjacent points within `bounds`.
+	pub fn neighbours4(self, bounds: Bounds<T>) -> impl Iterator<Item=Self> {
+		Direction4::ALL.into_iter().filter_map(move |d| self.step_within(d, bounds))
+	}
+
+	/// The up to 8 orthogonally or diagonally adjacent points within
+	/// `bounds`.
+	pub fn neighbours8(self, bounds: Bounds<T>) -> impl Iterator<Item=Self> {
+		Direction8::ALL.into_iter().filter_map(move |d| self.step_within(d, bounds))
+	}
+
+	/// The direction of the step that gets closest to `other`, moving
+	/// diagonally unless in line with it, or `None` if it's the same point.
+	pub fn direction_to(self, other: Self) -> Option<Direction8> {
+		let sign = |ord| match ord {
+			Ordering::Less => -1,
+			Ordering::Equal => 0,
+			Ordering::Greater => 1,
+		};
+		let delta = (sign(other.x.cmp(&self.x)), sign(other.y.cmp(&self.y)));
+		Direction8::from_delta(delta)
+	}
+}
+
+impl<T: Add<Output=T>> Add for Point<T> {
+	type Output = Self;
+	fn add(self, other: Self) -> Self {
+		Self::new(self.x + other.x, self.y + other.y)
+	}
+}
+
+impl<T: Sub<Output=T>> Sub for Point<T> {
+	type Output = Self;
+	fn sub(self, other: Self) -> Self {
+		Self::new(self.x - other.x, self.y - other.y)
+	}
+}
+
+impl<T: AddAssign> AddAssign for Point<T> {
+	fn add_assign(&mut self, other: Self) {
+		self.x += other.x;
+		self.y += other.y;
+	}
+}
+
+impl<T: SubAssign> SubAssign for Point<T> {
+	fn sub_assign(&mut self, other: Self) {
+		self.x -= other.x;
+		self.y -= other.y;
+	}
+}
+
+impl<T: Neg<Output=T>> Neg for Point<T> {
+	type Output = Self;
+	fn neg(self) -> Self {
+		Self::new(-self.x, -self.y)
+	}
+}
+
+impl<T: Mul<Output=T> + Copy> Mul<T> for Point<T> {
+	type Output = Self;
+	fn mul(self, k: T) -> Self {
+		Self::new(self.x * k, self.y * k)
+	}
+}
+
+impl<T: fmt::Display> fmt::Display for Point<T> {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		write!(f, "{},{}", self.x, self.y)
+	}
+}
+
+impl<T: ToJson> ToJson for Point<T> {
+	fn to_json(&self) -> Json {
+		Json::object([("x", self.x.to_json()), ("y", self.y.to_json())])
+	}
+}
+
+/// An axis-aligned rectangle of points, from `start` included to `end`
+/// excluded on both axes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Bounds<T> {
+	pub start: Point<T>,
+	pub end: Point<T>,
+}
+
+impl<T: Coord> Bounds<T> {
+	pub fn new(start: Point<T>, end: Point<T>) -> Self {
+		Self { start, end }
+	}
+
+	pub fn contains(&self, p: Point<T>) -> bool {
+		self.start.x <= p.x && p.x < self.end.x
+			&& self.start.y <= p.y && p.y < self.end.y
+	}
+}
+
+impl Bounds<usize> {
+	/// The points of a `width` by `height` grid.
+	pub fn of_size(width: usize, height: usize) -> Self {
+		Self::new(Point::new(0, 0), Point::new(width, height))
+	}
+}
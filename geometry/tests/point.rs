@@ -0,0 +1,81 @@
+use geometry::{Bounds, Direction4, Direction8, Point};
+
+fn p(x: i64, y: i64) -> Point<i64> {
+	Point::new(x, y)
+}
+
+// The points from `a` to `b` when always stepping towards `b`
+fn walk(a: Point<i64>, b: Point<i64>) -> Vec<Point<i64>> {
+	let mut line = vec![a];
+	let mut cur = a;
+	while let Some(dir) = cur.direction_to(b) {
+		cur = cur.step(dir);
+		line.push(cur);
+	}
+	line
+}
+
+#[test]
+fn distances() {
+	// Day 15's sensor and beacon, on both sides of the origin
+	assert_eq!(p(8, 7).manhattan(p(2, 10)), 9);
+	assert_eq!(p(-3, 4).manhattan(p(2, -1)), 10);
+	assert_eq!(p(5, 5).manhattan(p(5, 5)), 0);
+	assert_eq!(p(i64::MIN, 0).manhattan(p(i64::MAX, 0)), u64::MAX);
+	assert_eq!(Point::<u8>::new(0, 255).manhattan(Point::new(255, 0)), 510);
+
+	assert_eq!(p(8, 7).chebyshev(p(2, 10)), 6);
+	assert_eq!(p(-1, -1).chebyshev(p(1, 1)), 2);
+	for dir in Direction8::ALL {
+		assert_eq!(p(0, 0).step(dir).chebyshev(p(0, 0)), 1);
+	}
+}
+
+#[test]
+fn lines_are_walked_step_by_step() {
+	// Day 14's rock paths are horizontal or vertical
+	assert_eq!(walk(p(498, 4), p(498, 6)), [p(498, 4), p(498, 5), p(498, 6)]);
+	assert_eq!(walk(p(498, 6), p(496, 6)), [p(498, 6), p(497, 6), p(496, 6)]);
+	assert_eq!(walk(p(3, 3), p(0, 0)), [p(3, 3), p(2, 2), p(1, 1), p(0, 0)]);
+	assert_eq!(walk(p(1, 1), p(1, 1)), [p(1, 1)]);
+	// Off the axes, diagonally until in line
+	assert_eq!(walk(p(0, 0), p(3, 1)), [p(0, 0), p(1, 1), p(2, 1), p(3, 1)]);
+
+	assert_eq!(p(0, 0).direction_to(p(0, -5)), Some(Direction8::Up));
+	assert_eq!(p(0, 0).direction_to(p(-2, 9)), Some(Direction8::DownLeft));
+	assert_eq!(p(0, 0).direction_to(p(0, 0)), None);
+}
+
+#[test]
+fn steps_stop_at_the_edges() {
+	let origin = Point::<usize>::new(0, 0);
+	assert_eq!(origin.checked_step(Direction4::Up), None);
+	assert_eq!(origin.checked_step(Direction8::DownLeft), None);
+	assert_eq!(origin.checked_step(Direction8::DownRight), Some(Point::new(1, 1)));
+	assert_eq!(Point::new(u8::MAX, 0).checked_step(Direction4::Right), None);
+
+	let bounds = Bounds::new(p(-1, -1), p(2, 2));
+	assert!(bounds.contains(p(-1, -1)));
+	assert!(bounds.contains(p(1, 1)));
+	assert!(!bounds.contains(p(2, 0)));
+	assert!(!bounds.contains(p(0, -2)));
+	assert_eq!(p(1, 0).step_within(Direction4::Right, bounds), None);
+	assert_eq!(p(1, 0).step_within(Direction4::Left, bounds), Some(p(0, 0)));
+	assert!(!Bounds::of_size(0, 0).contains(Point::new(0, 0)));
+}
+
+#[test]
+fn neighbours_lie_within_bounds() {
+	let bounds = Bounds::of_size(3, 2);
+	let at = |x, y| Point::<usize>::new(x, y);
+	// Clockwise from up
+	assert_eq!(at(1, 0).neighbours4(bounds).collect::<Vec<_>>(), [at(2, 0), at(1, 1), at(0, 0)]);
+	assert_eq!(at(0, 0).neighbours8(bounds).collect::<Vec<_>>(), [at(1, 0), at(1, 1), at(0, 1)]);
+	assert_eq!(at(1, 1).neighbours8(bounds).count(), 5);
+	assert_eq!(at(2, 1).neighbours4(bounds).collect::<Vec<_>>(), [at(2, 0), at(1, 1)]);
+
+	let single = Bounds::of_size(1, 1);
+	assert_eq!(at(0, 0).neighbours8(single).count(), 0);
+	assert_eq!(p(0, 0).neighbours4(Bounds::new(p(-5, -5), p(5, 5))).count(), 4);
+	assert_eq!(p(0, 0).neighbours8(Bounds::new(p(-5, -5), p(5, 5))).count(), 8);
+}
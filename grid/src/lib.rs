@@ -0,0 +1,211 @@
+use std::fmt;
+use std::ops::{Index, IndexMut};
+
+use common::{Json, ParseError, ToJson};
+use geometry::{Bounds, Point};
+
+/// A position in a grid, as `(row, col)`.
+pub type Pos = (usize, usize);
+
+/// A rectangular grid with row-major storage.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+	width: usize,
+	height: usize,
+	cells: Vec<T>,
+}
+
+impl<T: Clone> Grid<T> {
+	pub fn new(width: usize, height: usize, fill: T) -> Self {
+		Self { width, height, cells: vec![fill; width * height] }
+	}
+}
+
+impl<T> Grid<T> {
+	pub fn from_fn<F>(width: usize, height: usize, mut f: F) -> Self
+	where
+		F: FnMut(Pos) -> T
+	{
+		let cells = (0..height)
+			.flat_map(|row| (0..width).map(move |col| (row, col)))
+			.map(&mut f)
+			.collect();
+		Self { width, height, cells }
+	}
+
+	/// Parses a character map, one row per non-empty line, mapping each
+	/// character to a cell. The mapping function returns what it expected
+	/// when it rejects a character.
+	pub fn parse<F, E>(st: &str, mut f: F) -> Result<Self, ParseError>
+	where
+		F: FnMut(char) -> Result<T, E>,
+		E: Into<String>,
+	{
+		let mut width = None;
+		let mut height = 0;
+		let mut cells = Vec::new();
+
+		for (lineno, line) in common::lines(st) {
+			let start = cells.len();
+			for (col, c) in line.chars().enumerate() {
+				let cell = f(c).map_err(|e| ParseError::new(col,
+					&c.to_string(), e).at_line(lineno))?;
+				cells.push(cell);
+			}
+
+			let len = cells.len() - start;
+			match width {
+				None => width = Some(len),
+				Some(w) if w != len => return Err(ParseError::new(0, line,
+					format!("a row of width {}", w)).at_line(lineno)),
+				Some(_) => (),
+			}
+			height += 1;
+		}
+
+		Ok(Self { width: width.unwrap_or(0), height, cells })
+	}
+
+	pub fn width(&self) -> usize {
+		self.width
+	}
+
+	pub fn height(&self) -> usize {
+		self.height
+	}
+
+	/// The bounds of the grid's points, `x` being the column and `y` the row.
+	pub fn bounds(&self) -> Bounds<usize> {
+		Bounds::of_size(self.width, self.height)
+	}
+
+	pub fn in_bounds(&self, (row, col): Pos) -> bool {
+		row < self.height && col < self.width
+	}
+
+	pub fn get(&self, pos: Pos) -> Option<&T> {
+		self.in_bounds(pos).then(|| &self.cells[pos.0 * self.width + pos.1])
+	}
+
+	pub fn get_mut(&mut self, pos: Pos) -> Option<&mut T> {
+		match self.in_bounds(pos) {
+			true => Some(&mut self.cells[pos.0 * self.width + pos.1]),
+			false => None,
+		}
+	}
+
+	pub fn row(&self, row: usize) -> &[T] {
+		&self.cells[row * self.width..(row + 1) * self.width]
+	}
+
+	pub fn rows(&self) -> impl Iterator<Item=&[T]> + '_ {
+		self.cells.chunks(self.width.max(1))
+	}
+
+	pub fn column(&self, col: usize) -> impl Iterator<Item=&T> + '_ {
+		assert!(col < self.width);
+		self.cells[col..].iter().step_by(self.width)
+	}
+
+	pub fn columns(&self) -> impl Iterator<Item=impl Iterator<Item=&T>> + '_ {
+		(0..self.width).map(|col| self.column(col))
+	}
+
+	/// Iterates over all positions, row by row.
+	pub fn positions(&self) -> impl Iterator<Item=Pos> {
+		let width = self.width;
+		(0..self.height)
+			.flat_map(move |row| (0..width).map(move |col| (row, col)))
+	}
+
+	/// Iterates over all positions as points, row by row.
+	pub fn points(&self) -> impl Iterator<Item=Point<usize>> {
+		self.positions().map(|(row, col)| Point::new(col, row))
+	}
+
+	pub fn iter(&self) -> impl Iterator<Item=(Pos, &T)> + '_ {
+		self.positions().zip(self.cells.iter())
+	}
+
+	pub fn iter_mut(&mut self) -> impl Iterator<Item=&mut T> + '_ {
+		self.cells.iter_mut()
+	}
+
+	pub fn position<P>(&self, mut pred: P) -> Option<Pos>
+	where
+		P: FnMut(&T) -> bool
+	{
+		self.cells.iter()
+			.position(&mut pred)
+			.map(|i| (i / self.width, i % self.width))
+	}
+
+	pub fn map<U, F>(&self, f: F) -> Grid<U>
+	where
+		F: FnMut(&T) -> U
+	{
+		Grid {
+			width: self.width,
+			height: self.height,
+			cells: self.cells.iter().map(f).collect(),
+		}
+	}
+
+	/// The up to 4 orthogonally adjacent positions within bounds.
+	pub fn neighbours4(&self, (row, col): Pos) -> impl Iterator<Item=Pos> {
+		Point::new(col, row).neighbours4(self.bounds()).map(|p| (p.y, p.x))
+	}
+
+	/// The up to 8 orthogonally or diagonally adjacent positions within
+	/// bounds.
+	pub fn neighbours8(&self, (row, col): Pos) -> impl Iterator<Item=Pos> {
+		Point::new(col, row).neighbours8(self.bounds()).map(|p| (p.y, p.x))
+	}
+}
+
+impl<T> Index<Pos> for Grid<T> {
+	type Output = T;
+	fn index(&self, pos: Pos) -> &Self::Output {
+		self.get(pos).expect("position out of bounds")
+	}
+}
+
+impl<T> IndexMut<Pos> for Grid<T> {
+	fn index_mut(&mut self, pos: Pos) -> &mut Self::Output {
+		self.get_mut(pos).expect("position out of bounds")
+	}
+}
+
+impl<T> Index<Point<usize>> for Grid<T> {
+	type Output = T;
+	fn index(&self, p: Point<usize>) -> &Self::Output {
+		&self[(p.y, p.x)]
+	}
+}
+
+impl<T> IndexMut<Point<usize>> for Grid<T> {
+	fn index_mut(&mut self, p: Point<usize>) -> &mut Self::Output {
+		&mut self[(p.y, p.x)]
+	}
+}
+
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		for (i, row) in self.rows().enumerate() {
+			if i > 0 {
+				writeln!(f)?;
+			}
+			for cell in row {
+				write!(f, "{}", cell)?;
+			}
+		}
+		Ok(())
+	}
+}
+
+// An array of rows
+impl<T: ToJson> ToJson for Grid<T> {
+	fn to_json(&self) -> Json {
+		Json::Array(self.rows().map(|row| row.to_json()).collect())
+	}
+}
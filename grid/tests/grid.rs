@@ -0,0 +1,75 @@
+use geometry::Point;
+use grid::Grid;
+
+fn digits(s: &str) -> Grid<u32> {
+	Grid::parse(s, |c| c.to_digit(10).ok_or("a digit")).unwrap()
+}
+
+fn sorted(it: impl Iterator<Item=(usize, usize)>) -> Vec<(usize, usize)> {
+	let mut v: Vec<_> = it.collect();
+	v.sort();
+	v
+}
+
+#[test]
+fn parse_reads_rows() {
+	let g = digits("123\n456\n");
+	assert_eq!((g.width(), g.height()), (3, 2));
+	assert_eq!(g[(1, 0)], 4);
+	assert_eq!(g[Point::new(2, 0)], 3);
+	assert_eq!(g.row(1), [4, 5, 6]);
+	assert_eq!(g.column(2).copied().collect::<Vec<_>>(), [3, 6]);
+	assert_eq!(g.position(|&d| d == 5), Some((1, 1)));
+	assert_eq!(g.to_string(), "123\n456");
+
+	let empty = digits("");
+	assert_eq!((empty.width(), empty.height()), (0, 0));
+	assert_eq!(empty.rows().count(), 0);
+}
+
+#[test]
+fn parse_rejects_bad_rows() {
+	let parse = |s| Grid::parse(s, |c| c.to_digit(10).ok_or("a digit")).unwrap_err().to_string();
+	assert_eq!(parse("12\n3x\n"), "line 2, column 2: expected a digit, found `x`");
+	assert_eq!(parse("12\n345\n"), "line 2, column 1: expected a row of width 2, found `345`");
+}
+
+#[test]
+fn bounds_match_the_cells() {
+	let mut g = Grid::new(3, 2, 0);
+	assert!(g.in_bounds((1, 2)));
+	assert!(!g.in_bounds((2, 0)));
+	assert!(!g.in_bounds((0, 3)));
+	assert_eq!(g.get((0, 3)), None);
+	assert_eq!(g.get_mut((2, 2)), None);
+	*g.get_mut((1, 2)).unwrap() = 7;
+	assert_eq!(g.get((1, 2)), Some(&7));
+
+	let bounds = g.bounds();
+	assert!(g.points().all(|p| bounds.contains(p)));
+	assert_eq!(g.points().count(), 6);
+	assert!(!bounds.contains(Point::new(3, 0)));
+	assert!(!bounds.contains(Point::new(0, 2)));
+}
+
+#[test]
+fn neighbours_stay_in_the_grid() {
+	let g = Grid::new(4, 3, ());
+	assert_eq!(sorted(g.neighbours4((0, 0))), [(0, 1), (1, 0)]);
+	assert_eq!(sorted(g.neighbours4((1, 1))), [(0, 1), (1, 0), (1, 2), (2, 1)]);
+	assert_eq!(sorted(g.neighbours4((2, 3))), [(1, 3), (2, 2)]);
+	assert_eq!(sorted(g.neighbours8((0, 0))), [(0, 1), (1, 0), (1, 1)]);
+	assert_eq!(sorted(g.neighbours8((2, 3))), [(1, 2), (1, 3), (2, 2)]);
+	assert_eq!(g.neighbours8((1, 2)).count(), 8);
+	assert_eq!(sorted(g.neighbours8((0, 1))), [(0, 0), (0, 2), (1, 0), (1, 1), (1, 2)]);
+
+	let single = Grid::new(1, 1, ());
+	assert_eq!(single.neighbours8((0, 0)).count(), 0);
+}
+
+#[test]
+#[should_panic(expected = "position out of bounds")]
+fn indexing_out_of_bounds_panics() {
+	let g = Grid::new(2, 2, 0);
+	let _ = g[(0, 2)];
+}
@@ -0,0 +1,161 @@
+use std::cmp;
+use std::iter;
+
+/// A closed integer interval `[start, end]`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Interval {
+	pub start: i64,
+	pub end: i64,
+}
+
+impl Interval {
+	pub fn new(start: i64, end: i64) -> Self {
+		assert!(start <= end, "empty interval [{}, {}]", start, end);
+		Self { start, end }
+	}
+
+	/// Number of integers covered by the interval
+	#[allow(clippy::len_without_is_empty)]
+	pub fn len(&self) -> u64 {
+		self.start.abs_diff(self.end) + 1
+	}
+
+	pub fn contains(&self, v: i64) -> bool {
+		self.start <= v && v <= self.end
+	}
+
+	pub fn contains_interval(&self, other: &Self) -> bool {
+		self.start <= other.start && other.end <= self.end
+	}
+
+	pub fn overlaps(&self, other: &Self) -> bool {
+		self.start <= other.end && other.start <= self.end
+	}
+
+	pub fn intersection(&self, other: &Self) -> Option<Self> {
+		let start = cmp::max(self.start, other.start);
+		let end = cmp::min(self.end, other.end);
+		(start <= end).then_some(Self { start, end })
+	}
+}
+
+/// A set of integers stored as sorted, disjoint and non-adjacent
+/// closed intervals. Inserted intervals are merged with any interval
+/// they overlap or touch.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct IntervalSet {
+	ivs: Vec<Interval>,
+}
+
+impl IntervalSet {
+	pub fn new() -> Self {
+		Self::default()
+	}
+
+	pub fn is_empty(&self) -> bool {
+		self.ivs.is_empty()
+	}
+
+	/// Total number of integers covered by the set
+	pub fn len(&self) -> u64 {
+		self.ivs.iter().map(Interval::len).sum()
+	}
+
+	pub fn clear(&mut self) {
+		self.ivs.clear();
+	}
+
+	pub fn iter(&self) -> impl Iterator<Item=&Interval> + '_ {
+		self.ivs.iter()
+	}
+
+	pub fn contains(&self, v: i64) -> bool {
+		let i = self.ivs.partition_point(|iv| iv.end < v);
+		self.ivs.get(i).is_some_and(|iv| iv.contains(v))
+	}
+
+	pub fn insert(&mut self, iv: Interval) {
+		// Intervals in lo..hi overlap or touch the new one
+		let lo = self.ivs.partition_point(|x| x.end < iv.start.saturating_sub(1));
+		let hi = self.ivs.partition_point(|x| x.start <= iv.end.saturating_add(1));
+
+		let mut merged = iv;
+		if lo < hi {
+			merged.start = cmp::min(merged.start, self.ivs[lo].start);
+			merged.end = cmp::max(merged.end, self.ivs[hi - 1].end);
+		}
+		self.ivs.splice(lo..hi, iter::once(merged));
+	}
+
+	pub fn remove(&mut self, iv: Interval) {
+		// Intervals in lo..hi overlap the removed one
+		let lo = self.ivs.partition_point(|x| x.end < iv.start);
+		let hi = self.ivs.partition_point(|x| x.start <= iv.end);
+		if lo >= hi {
+			return;
+		}
+
+		// Only the outermost intervals may be partially kept
+		let first = self.ivs[lo];
+		let last = self.ivs[hi - 1];
+		let left = (first.start < iv.start)
+			.then(|| Interval::new(first.start, iv.start - 1));
+		let right = (last.end > iv.end)
+			.then(|| Interval::new(iv.end + 1, last.end));
+		self.ivs.splice(lo..hi, left.into_iter().chain(right));
+	}
+
+	pub fn union(&self, other: &Self) -> Self {
+		let mut out = self.clone();
+		out.extend(other.iter().copied());
+		out
+	}
+
+	pub fn intersection(&self, other: &Self) -> Self {
+		let mut ivs = Vec::new();
+		let (mut i, mut j) = (0, 0);
+
+		while i < self.ivs.len() && j < other.ivs.len() {
+			let (a, b) = (&self.ivs[i], &other.ivs[j]);
+			if let Some(iv) = a.intersection(b) {
+				ivs.push(iv);
+			}
+			match a.end < b.end {
+				true => i += 1,
+				false => j += 1,
+			}
+		}
+
+		Self { ivs }
+	}
+
+	pub fn difference(&self, other: &Self) -> Self {
+		let mut out = self.clone();
+		for iv in other.iter() {
+			out.remove(*iv);
+		}
+		out
+	}
+}
+
+impl From<Interval> for IntervalSet {
+	fn from(iv: Interval) -> Self {
+		Self { ivs: vec![iv] }
+	}
+}
+
+impl Extend<Interval> for IntervalSet {
+	fn extend<I: IntoIterator<Item=Interval>>(&mut self, iter: I) {
+		for iv in iter {
+			self.insert(iv);
+		}
+	}
+}
+
+impl FromIterator<Interval> for IntervalSet {
+	fn from_iter<I: IntoIterator<Item=Interval>>(iter: I) -> Self {
+		let mut out = Self::new();
+		out.extend(iter);
+		out
+	}
+}
@@ -0,0 +1,120 @@
+use intervals::{Interval, IntervalSet};
+
+fn set(ivs: &[(i64, i64)]) -> IntervalSet {
+	ivs.iter().map(|&(start, end)| Interval::new(start, end)).collect()
+}
+
+fn ivs(set: &IntervalSet) -> Vec<(i64, i64)> {
+	set.iter().map(|iv| (iv.start, iv.end)).collect()
+}
+
+#[test]
+fn insert_merges_overlapping_and_adjacent() {
+	assert_eq!(ivs(&set(&[(0, 5), (3, 8)])), [(0, 8)]);
+	assert_eq!(ivs(&set(&[(0, 5), (6, 8)])), [(0, 8)]);
+	assert_eq!(ivs(&set(&[(6, 8), (0, 5)])), [(0, 8)]);
+	// A gap of a single integer keeps them apart
+	assert_eq!(ivs(&set(&[(0, 5), (7, 8)])), [(0, 5), (7, 8)]);
+	// An interval bridging several swallows them all
+	assert_eq!(ivs(&set(&[(0, 1), (4, 5), (8, 9), (12, 13), (2, 10)])),
+		[(0, 10), (12, 13)]);
+	assert_eq!(ivs(&set(&[(0, 10), (3, 4)])), [(0, 10)]);
+	assert_eq!(ivs(&set(&[(i64::MIN, 0), (1, i64::MAX)])), [(i64::MIN, i64::MAX)]);
+}
+
+#[test]
+fn remove_splits_and_trims() {
+	let mut s = set(&[(0, 10)]);
+	s.remove(Interval::new(3, 5));
+	assert_eq!(ivs(&s), [(0, 2), (6, 10)]);
+
+	// Removing the ends keeps the rest of the outermost intervals
+	let mut s = set(&[(0, 4), (6, 10), (12, 16)]);
+	s.remove(Interval::new(2, 13));
+	assert_eq!(ivs(&s), [(0, 1), (14, 16)]);
+
+	let mut s = set(&[(0, 4), (6, 10)]);
+	s.remove(Interval::new(0, 4));
+	assert_eq!(ivs(&s), [(6, 10)]);
+	s.remove(Interval::new(5, 5));
+	assert_eq!(ivs(&s), [(6, 10)]);
+	s.remove(Interval::new(10, 20));
+	assert_eq!(ivs(&s), [(6, 9)]);
+	s.remove(Interval::new(6, 6));
+	assert_eq!(ivs(&s), [(7, 9)]);
+	s.remove(Interval::new(-5, 100));
+	assert!(s.is_empty());
+}
+
+#[test]
+fn len_and_contains() {
+	let s = set(&[(-3, 3), (10, 10)]);
+	assert_eq!(s.len(), 8);
+	assert!(s.contains(-3) && s.contains(3) && s.contains(10));
+	assert!(!s.contains(-4) && !s.contains(4) && !s.contains(9) && !s.contains(11));
+	assert_eq!(Interval::new(i64::MIN, i64::MAX - 1).len(), u64::MAX);
+}
+
+#[test]
+fn interval_relations() {
+	let a = Interval::new(2, 4);
+	assert!(a.contains_interval(&Interval::new(2, 4)));
+	assert!(a.contains_interval(&Interval::new(3, 3)));
+	assert!(!a.contains_interval(&Interval::new(1, 3)));
+	assert!(a.overlaps(&Interval::new(4, 6)));
+	assert!(a.overlaps(&Interval::new(0, 2)));
+	assert!(!a.overlaps(&Interval::new(5, 6)));
+}
+
+#[test]
+fn interval_intersection() {
+	let a = Interval::new(2, 6);
+	assert_eq!(a.intersection(&Interval::new(4, 9)), Some(Interval::new(4, 6)));
+	assert_eq!(a.intersection(&Interval::new(3, 4)), Some(Interval::new(3, 4)));
+	assert_eq!(a.intersection(&Interval::new(6, 9)), Some(Interval::new(6, 6)));
+	assert_eq!(a.intersection(&Interval::new(7, 9)), None);
+}
+
+#[test]
+fn union_of_sets() {
+	let empty = IntervalSet::new();
+	// Disjoint operands stay apart, touching ones merge
+	assert_eq!(ivs(&set(&[(0, 2)]).union(&set(&[(5, 7)]))), [(0, 2), (5, 7)]);
+	assert_eq!(ivs(&set(&[(0, 2)]).union(&set(&[(3, 7)]))), [(0, 7)]);
+	// Nested intervals and ones bridging gaps
+	assert_eq!(ivs(&set(&[(0, 10)]).union(&set(&[(2, 3), (5, 6)]))), [(0, 10)]);
+	assert_eq!(ivs(&set(&[(0, 2), (6, 8)]).union(&set(&[(1, 7), (10, 11)]))),
+		[(0, 8), (10, 11)]);
+	assert_eq!(ivs(&set(&[(0, 2)]).union(&empty)), [(0, 2)]);
+	assert_eq!(ivs(&empty.union(&set(&[(0, 2)]))), [(0, 2)]);
+	assert!(empty.union(&empty).is_empty());
+}
+
+#[test]
+fn intersection_of_sets() {
+	let empty = IntervalSet::new();
+	assert!(set(&[(0, 2)]).intersection(&set(&[(5, 7)])).is_empty());
+	// Touching sets have nothing in common, sharing an end point does
+	assert!(set(&[(0, 2)]).intersection(&set(&[(3, 7)])).is_empty());
+	assert_eq!(ivs(&set(&[(0, 3)]).intersection(&set(&[(3, 7)]))), [(3, 3)]);
+	assert_eq!(ivs(&set(&[(0, 10)]).intersection(&set(&[(2, 3), (5, 6)]))),
+		[(2, 3), (5, 6)]);
+	assert_eq!(ivs(&set(&[(0, 4), (6, 10)]).intersection(&set(&[(2, 8), (10, 12)]))),
+		[(2, 4), (6, 8), (10, 10)]);
+	assert!(set(&[(0, 2)]).intersection(&empty).is_empty());
+	assert!(empty.intersection(&set(&[(0, 2)])).is_empty());
+}
+
+#[test]
+fn difference_of_sets() {
+	let empty = IntervalSet::new();
+	assert_eq!(ivs(&set(&[(0, 2)]).difference(&set(&[(5, 7)]))), [(0, 2)]);
+	assert_eq!(ivs(&set(&[(0, 2)]).difference(&set(&[(3, 7)]))), [(0, 2)]);
+	assert_eq!(ivs(&set(&[(0, 5)]).difference(&set(&[(3, 7)]))), [(0, 2)]);
+	// Nested operands split the outer interval, or remove it whole
+	assert_eq!(ivs(&set(&[(0, 10)]).difference(&set(&[(2, 3), (5, 6)]))),
+		[(0, 1), (4, 4), (7, 10)]);
+	assert!(set(&[(2, 3), (5, 6)]).difference(&set(&[(0, 10)])).is_empty());
+	assert_eq!(ivs(&set(&[(0, 2)]).difference(&empty)), [(0, 2)]);
+	assert!(empty.difference(&set(&[(0, 2)])).is_empty());
+}
@@ -0,0 +1,21 @@
+use common::Solution;
+
+mod player;
+
+pub use player::play;
+
+/// A simulation that can be replayed one step at a time.
+///
+/// Step 0 is the initial state, and `steps()` the state once the
+/// simulation is over.
+pub trait Simulation {
+	fn steps(&self) -> usize;
+
+	/// Renders the state after `step` steps, for any step up to `steps()`.
+	fn render(&self, step: usize) -> String;
+}
+
+/// A solution whose parts can be watched as they are computed.
+pub trait Visualize: Solution {
+	fn simulation(input: &Self::Input, part: u8) -> Box<dyn Simulation>;
+}
@@ -0,0 +1,152 @@
+use std::io::{self, BufRead, Write};
+use std::sync::mpsc::{self, RecvTimeoutError};
+use std::thread;
+use std::time::Duration;
+
+use crate::Simulation;
+
+const HELP: &str = "\
+[enter] step  [b] back  [p] play/pause  [+/-] speed  [g N] go to step N  [q] quit";
+
+// Frames are drawn at most this often; faster speeds skip steps instead
+const MAX_FPS: u64 = 30;
+
+#[derive(Clone, Copy)]
+enum Command {
+	Step,
+	Back,
+	Toggle,
+	Faster,
+	Slower,
+	Jump(usize),
+	Quit,
+}
+
+impl Command {
+	fn parse(line: &str) -> Result<Self, String> {
+		let line = line.trim();
+		let (cmd, arg) = line.split_once(' ').unwrap_or((line, ""));
+		match (cmd, arg.trim()) {
+			("" | "s", "") => Ok(Self::Step),
+			("b", "") => Ok(Self::Back),
+			("p", "") => Ok(Self::Toggle),
+			("+", "") => Ok(Self::Faster),
+			("-", "") => Ok(Self::Slower),
+			("q", "") => Ok(Self::Quit),
+			("g", n) | (n, "") => n.parse::<usize>()
+				.map(Self::Jump)
+				.map_err(|_| format!("unknown command: {}", line)),
+			_ => Err(format!("unknown command: {}", line)),
+		}
+	}
+}
+
+struct Player {
+	step: usize,
+	last: usize,
+	playing: bool,
+	// Steps per second while playing
+	speed: u64,
+	message: String,
+}
+
+impl Player {
+	fn apply(&mut self, cmd: Command) -> bool {
+		match cmd {
+			Command::Step => {
+				self.playing = false;
+				self.step = (self.step + 1).min(self.last);
+			}
+			Command::Back => {
+				self.playing = false;
+				self.step = self.step.saturating_sub(1);
+			}
+			Command::Toggle => self.playing = !self.playing,
+			Command::Faster => self.speed = (self.speed * 2).min(1 << 20),
+			Command::Slower => self.speed = (self.speed / 2).max(1),
+			Command::Jump(n) => {
+				self.playing = false;
+				self.step = n.min(self.last);
+			}
+			Command::Quit => return false,
+		}
+		true
+	}
+
+	fn frame(&self) -> Duration {
+		Duration::from_millis(1000 / self.speed.min(MAX_FPS))
+	}
+
+	fn advance(&mut self) {
+		let stride = self.speed.div_ceil(MAX_FPS) as usize;
+		self.step = (self.step + stride).min(self.last);
+		if self.step == self.last {
+			self.playing = false;
+		}
+	}
+
+	fn draw(&self, sim: &dyn Simulation, out: &mut impl Write) -> io::Result<()> {
+		// Clear the screen and move to its top left corner
+		write!(out, "\x1b[2J\x1b[H")?;
+		writeln!(out, "{}", sim.render(self.step))?;
+		writeln!(out)?;
+		writeln!(out, "step {}/{}  {}  {} steps/s",
+			self.step, self.last,
+			if self.playing { "playing" } else { "paused" },
+			self.speed)?;
+		writeln!(out, "{}", HELP)?;
+		if !self.message.is_empty() {
+			writeln!(out, "{}", self.message)?;
+		}
+		out.flush()
+	}
+}
+
+/// Replays a simulation in the terminal, reading commands from stdin one
+/// line at a time. Returns once the user quits or stdin is closed.
+pub fn play(sim: &dyn Simulation) -> io::Result<()> {
+	// Lines are read on their own thread so that playback can go on
+	// while waiting for the next command
+	let (tx, rx) = mpsc::channel();
+	thread::spawn(move || {
+		for line in io::stdin().lock().lines() {
+			if line.map(|l| tx.send(l)).is_err() {
+				break;
+			}
+		}
+	});
+
+	let mut out = io::stdout().lock();
+	let mut player = Player {
+		step: 0,
+		last: sim.steps(),
+		playing: false,
+		speed: 10,
+		message: String::new(),
+	};
+
+	loop {
+		player.draw(sim, &mut out)?;
+		let line = match player.playing {
+			true => rx.recv_timeout(player.frame()),
+			false => rx.recv().map_err(|_| RecvTimeoutError::Disconnected),
+		};
+		match line {
+			Ok(line) => {
+				player.message.clear();
+				match Command::parse(&line) {
+					Ok(cmd) if !player.apply(cmd) => return Ok(()),
+					Ok(_) => (),
+					Err(e) => player.message = e,
+				}
+			}
+			Err(RecvTimeoutError::Timeout) => player.advance(),
+			// Keep playing to the end once there is no more input
+			Err(RecvTimeoutError::Disconnected) if player.playing => {
+				thread::sleep(player.frame());
+				player.advance();
+			}
+			Err(RecvTimeoutError::Disconnected) => return Ok(()),
+		}
+	}
+}